@@ -17,7 +17,12 @@
         let
           craneLib = (inputs.crane.mkLib final).overrideToolchain rust-bin.nightly.latest.default;
           commonArgs = {
-            src = ./lib;
+            # The whole repository, so that lib can find the path dependency on macros.
+            src = ./.;
+            postUnpack = ''
+              cd $sourceRoot/lib
+              sourceRoot="."
+            '';
             pname = "antithesis-sdk-rust-workspace";
             version = "0.0.0";
           };
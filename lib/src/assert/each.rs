@@ -0,0 +1,169 @@
+#[cfg(feature = "full")]
+use std::borrow::Cow;
+#[cfg(feature = "full")]
+use std::collections::HashMap;
+#[cfg(feature = "full")]
+use std::sync::atomic::{AtomicBool, Ordering};
+#[cfg(feature = "full")]
+use std::sync::{Arc, RwLock};
+
+#[cfg(feature = "full")]
+use once_cell::sync::OnceCell;
+#[cfg(feature = "full")]
+use serde::Serialize;
+#[cfg(feature = "full")]
+use serde_json::{json, Value};
+
+#[cfg(feature = "full")]
+use crate::assert::{assert_impl, details_map, AssertType, AssertionSite, LazyMessage, TrackingInfo};
+#[cfg(feature = "full")]
+use crate::internal;
+
+/// The number of distinct keys tracked by an `assert_sometimes_each!` call site, unless ``max_keys`` is given.
+pub const DEFAULT_MAX_KEYS: usize = 64;
+
+/// The name of the event emitted once when an `assert_sometimes_each!` call site
+/// sees more distinct keys than it tracks.
+pub const KEY_LIMIT_EVENT: &str = "antithesis_sometimes_each_key_limit";
+
+/// The keys seen by an `assert_sometimes_each!` call site, with a tracker per key.
+#[cfg(feature = "full")]
+pub struct KeyedTrackers {
+    keys: OnceCell<RwLock<HashMap<String, Arc<KeyEntry>>>>,
+    overflowed: AtomicBool,
+}
+
+// The property of a key, built when the key is first seen.
+#[cfg(feature = "full")]
+struct KeyEntry {
+    label: String,
+    id: String,
+    tracker: TrackingInfo,
+}
+
+#[cfg(feature = "full")]
+impl KeyedTrackers {
+    pub const fn new() -> Self {
+        Self {
+            keys: OnceCell::new(),
+            overflowed: AtomicBool::new(false),
+        }
+    }
+
+    // Returns the entry of the key serialized as ``json``, and whether it is seen for
+    // the first time, or None if the call site already tracks ``max_keys`` other keys.
+    fn entry(&self, json: &str, id: &str, max_keys: usize) -> Option<(Arc<KeyEntry>, bool)> {
+        let keys = self.keys.get_or_init(Default::default);
+        // Keys that are already tracked only need a shared lock
+        if let Some(entry) = keys.read().unwrap_or_else(|e| e.into_inner()).get(json) {
+            return Some((entry.clone(), false));
+        }
+        let mut keys = keys.write().unwrap_or_else(|e| e.into_inner());
+        if let Some(entry) = keys.get(json) {
+            return Some((entry.clone(), false));
+        }
+        if keys.len() >= max_keys {
+            return None;
+        }
+        let label = key_label(&serde_json::from_str(json).unwrap_or(Value::Null));
+        let entry = Arc::new(KeyEntry {
+            id: format!("{} [{}]", id, label),
+            label,
+            tracker: TrackingInfo::new(),
+        });
+        keys.insert(json.to_owned(), entry.clone());
+        Some((entry, true))
+    }
+}
+
+#[cfg(feature = "full")]
+impl Default for KeyedTrackers {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Keys are shown in the name of their property: strings as they are, and
+// everything else as compact JSON.
+#[cfg(feature = "full")]
+fn key_label(key: &Value) -> String {
+    match key {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+// The details of a hit with its key, merged when they are serialized, so
+// that only the hits that are emitted pay for it.
+#[cfg(feature = "full")]
+struct KeyedDetails<'a, K: ?Sized, S: ?Sized> {
+    key: &'a K,
+    details: &'a S,
+}
+
+#[cfg(feature = "full")]
+impl<K: Serialize + ?Sized, S: Serialize + ?Sized> Serialize for KeyedDetails<'_, K, S> {
+    fn serialize<Ser: serde::Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        let mut details = details_map(self.details);
+        details.insert("key".to_owned(), serde_json::to_value(self.key).unwrap_or(Value::Null));
+        details.serialize(serializer)
+    }
+}
+
+/// Emits a hit of the property of the call site, and of the property of ``key``.
+#[cfg(feature = "full")]
+#[allow(clippy::too_many_arguments)]
+pub fn sometimes_each<K: Serialize + ?Sized, S: Serialize + ?Sized, M: Fn() -> Cow<'static, str>>(
+    site: AssertionSite,
+    trackers: &KeyedTrackers,
+    max_keys: usize,
+    key: &K,
+    condition: bool,
+    message: &LazyMessage<M>,
+    details: &S,
+) {
+    let details = KeyedDetails { key, details };
+    site.emit(condition, || message.get(), &details);
+
+    let info = site.info;
+    let json = serde_json::to_string(key).unwrap_or_else(|_| "null".to_owned());
+    let (entry, first) = match trackers.entry(&json, info.id, max_keys) {
+        Some(found) => found,
+        None => {
+            if !trackers.overflowed.swap(true, Ordering::SeqCst) {
+                internal::dispatch_output(&json!({ KEY_LIMIT_EVENT: {
+                    "id": info.id,
+                    "max_keys": max_keys,
+                    "dropped_key": serde_json::to_value(key).unwrap_or(Value::Null),
+                }}));
+            }
+            return;
+        }
+    };
+    let function: &str = info.function.as_ref();
+    let emit = |hit: bool, condition: bool, details: Option<&KeyedDetails<K, S>>| {
+        assert_impl(
+            AssertType::Sometimes,
+            info.display_type,
+            condition,
+            &format!("{} [{}]", message.get(), entry.label),
+            info.class,
+            function,
+            info.file,
+            info.begin_line,
+            info.begin_column,
+            hit,
+            info.must_hit,
+            &entry.id,
+            &details,
+            None,
+        )
+    };
+    // Keys cannot be known ahead of time, so their catalog entry is emitted when they are first seen
+    if first {
+        emit(false, false, None);
+    }
+    if entry.tracker.track(condition) {
+        emit(true, condition, Some(&details));
+    }
+}
@@ -0,0 +1,165 @@
+use serde::{Serialize, Serializer};
+use serde_json::{json, Value};
+
+use crate::assert::details_map;
+
+// Differences beyond this count are dropped, so that comparing large
+// values does not produce unbounded details.
+const MAX_DIFF_ENTRIES: usize = 32;
+
+/// Builds the details of an equality assertion: ``details`` merged with
+/// ``{"left": left, "right": right}``, plus a ``diff`` of the two operands
+/// when ``differ`` is true.
+///
+/// Details that are not a JSON object are kept under the ``details`` key.
+pub fn details<L, R, D>(left: &L, right: &R, details: &D, differ: bool) -> Value
+where
+    L: Serialize + ?Sized,
+    R: Serialize + ?Sized,
+    D: Serialize + ?Sized,
+{
+    let mut merged = details_map(details);
+    let left = serde_json::to_value(left).unwrap_or(Value::Null);
+    let right = serde_json::to_value(right).unwrap_or(Value::Null);
+    if differ {
+        let mut entries = Vec::new();
+        let truncated = !diff_into(&mut String::new(), &left, &right, &mut entries);
+        merged.insert("diff".to_owned(), Value::Array(entries));
+        if truncated {
+            merged.insert("diff_truncated".to_owned(), Value::Bool(true));
+        }
+    }
+    merged.insert("left".to_owned(), left);
+    merged.insert("right".to_owned(), right);
+    Value::Object(merged)
+}
+
+/// The details of an equality assertion, built by [`details`] only when they are
+/// serialized, so that the hits which are not emitted skip serializing and diffing
+/// the operands.
+pub struct EqualityDetails<'a, L: ?Sized, R: ?Sized, D: ?Sized> {
+    pub left: &'a L,
+    pub right: &'a R,
+    pub details: &'a D,
+}
+
+impl<L, R, D> Serialize for EqualityDetails<'_, L, R, D>
+where
+    L: Serialize + PartialEq<R> + ?Sized,
+    R: Serialize + ?Sized,
+    D: Serialize + ?Sized,
+{
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        details(self.left, self.right, self.details, self.left != self.right).serialize(serializer)
+    }
+}
+
+/// Lists the paths at which ``left`` and ``right`` differ, as JSON pointers,
+/// along with the value found on each side. A side is omitted when the path
+/// does not exist in it.
+pub fn diff(left: &Value, right: &Value) -> Vec<Value> {
+    let mut entries = Vec::new();
+    diff_into(&mut String::new(), left, right, &mut entries);
+    entries
+}
+
+// Returns false if entries had to be dropped.
+fn diff_into(path: &mut String, left: &Value, right: &Value, entries: &mut Vec<Value>) -> bool {
+    match (left, right) {
+        (Value::Object(l), Value::Object(r)) => {
+            let mut keys: Vec<&String> = l.keys().chain(r.keys().filter(|k| !l.contains_key(*k))).collect();
+            keys.sort();
+            keys.into_iter().all(|key| {
+                let len = path.len();
+                path.push('/');
+                path.push_str(&key.replace('~', "~0").replace('/', "~1"));
+                let complete = diff_sides(path, l.get(key), r.get(key), entries);
+                path.truncate(len);
+                complete
+            })
+        }
+        (Value::Array(l), Value::Array(r)) => (0..l.len().max(r.len())).all(|i| {
+            let len = path.len();
+            path.push('/');
+            path.push_str(&i.to_string());
+            let complete = diff_sides(path, l.get(i), r.get(i), entries);
+            path.truncate(len);
+            complete
+        }),
+        _ => diff_sides(path, Some(left), Some(right), entries),
+    }
+}
+
+fn diff_sides(path: &mut String, left: Option<&Value>, right: Option<&Value>, entries: &mut Vec<Value>) -> bool {
+    match (left, right) {
+        (Some(l), Some(r)) if l == r => true,
+        (Some(l @ Value::Object(_)), Some(r @ Value::Object(_))) | (Some(l @ Value::Array(_)), Some(r @ Value::Array(_))) => {
+            diff_into(path, l, r, entries)
+        }
+        _ => {
+            if entries.len() == MAX_DIFF_ENTRIES {
+                return false;
+            }
+            let mut entry = json!({ "path": path.as_str() });
+            if let Some(l) = left {
+                entry["left"] = l.clone();
+            }
+            if let Some(r) = right {
+                entry["right"] = r.clone();
+            }
+            entries.push(entry);
+            true
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_scalars() {
+        assert_eq!(diff(&json!(1), &json!(2)), vec![json!({"path": "", "left": 1, "right": 2})]);
+        assert!(diff(&json!("a"), &json!("a")).is_empty());
+    }
+
+    #[test]
+    fn diff_nested() {
+        let left = json!({"a": {"b": [1, 2]}, "c/d": 1, "gone": true});
+        let right = json!({"a": {"b": [1, 3]}, "c/d": 2, "new": null});
+        assert_eq!(
+            diff(&left, &right),
+            vec![
+                json!({"path": "/a/b/1", "left": 2, "right": 3}),
+                json!({"path": "/c~1d", "left": 1, "right": 2}),
+                json!({"path": "/gone", "left": true}),
+                json!({"path": "/new", "right": null}),
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_type_change() {
+        assert_eq!(
+            diff(&json!({"a": [1]}), &json!({"a": {"0": 1}})),
+            vec![json!({"path": "/a", "left": [1], "right": {"0": 1}})]
+        );
+    }
+
+    #[test]
+    fn details_truncated() {
+        let left: Vec<u32> = (0..100).collect();
+        let right: Vec<u32> = (1..101).collect();
+        let details = details(&left, &right, &json!({"node": 1}), true);
+        assert_eq!(details["diff"].as_array().unwrap().len(), MAX_DIFF_ENTRIES);
+        assert_eq!(details["diff_truncated"], true);
+        assert_eq!(details["node"], 1);
+        assert_eq!(details["left"][0], 0);
+    }
+
+    #[test]
+    fn details_without_diff() {
+        let details = details(&1, &1, &json!("note"), false);
+        assert_eq!(details, json!({"details": "note", "left": 1, "right": 1}));
+    }
+}
@@ -0,0 +1,249 @@
+use std::borrow::Cow;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use serde_json::{json, Map, Value};
+
+#[cfg(feature = "full")]
+use std::{collections::BTreeMap, sync::Mutex};
+#[cfg(feature = "full")]
+use once_cell::sync::Lazy;
+#[cfg(feature = "full")]
+use crate::assert::{details_map, AssertionSite};
+
+/// How long an [`Obligation`] may stay pending.
+///
+/// A deadline is a number of logical steps, an amount of wall time, or both,
+/// in which case the obligation expires when the first of them is reached.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use antithesis_sdk::assert::eventually::Deadline;
+///
+/// let deadline = Deadline::steps(100).or_within(Duration::from_secs(30));
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Deadline {
+    steps: Option<u64>,
+    within: Option<Duration>,
+}
+
+impl Deadline {
+    /// Expires once ``steps`` steps have been taken without resolving the obligation.
+    pub fn steps(steps: u64) -> Self {
+        Deadline { steps: Some(steps), within: None }
+    }
+
+    /// Expires once ``duration`` has elapsed since the obligation was created.
+    pub fn within(duration: Duration) -> Self {
+        Deadline { steps: None, within: Some(duration) }
+    }
+
+    /// Also expires once ``steps`` steps have been taken.
+    pub fn or_steps(self, steps: u64) -> Self {
+        Deadline { steps: Some(steps), ..self }
+    }
+
+    /// Also expires once ``duration`` has elapsed.
+    pub fn or_within(self, duration: Duration) -> Self {
+        Deadline { within: Some(duration), ..self }
+    }
+
+    fn to_json(self) -> Value {
+        json!({
+            "steps": self.steps,
+            "within_ms": self.within.map(|d| d.as_millis() as u64),
+        })
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum Outcome {
+    Resolved,
+    Expired,
+    Abandoned,
+    #[cfg(feature = "full")]
+    UnresolvedAtShutdown,
+}
+
+impl Outcome {
+    fn as_str(self) -> &'static str {
+        match self {
+            Outcome::Resolved => "resolved",
+            Outcome::Expired => "expired",
+            Outcome::Abandoned => "abandoned",
+            #[cfg(feature = "full")]
+            Outcome::UnresolvedAtShutdown => "unresolved at shutdown",
+        }
+    }
+}
+
+struct State {
+    #[cfg(feature = "full")]
+    site: AssertionSite,
+    #[cfg(feature = "full")]
+    key: u64,
+    message: Cow<'static, str>,
+    details: Map<String, Value>,
+    deadline: Deadline,
+    started: Instant,
+    steps: AtomicU64,
+    settled: AtomicBool,
+}
+
+impl State {
+    fn expired(&self) -> bool {
+        let steps = self.steps.load(Ordering::SeqCst);
+        self.deadline.steps.map_or(false, |limit| steps >= limit)
+            || self.deadline.within.map_or(false, |limit| self.started.elapsed() >= limit)
+    }
+
+    // Settles the obligation, emitting the property unless ``outcome`` is None.
+    // Only the first call has any effect.
+    fn settle(&self, outcome: Option<Outcome>) {
+        if self.settled.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        #[cfg(feature = "full")]
+        {
+            PENDING.lock().unwrap_or_else(|e| e.into_inner()).remove(&self.key);
+            if let Some(outcome) = outcome {
+                let details = self.details(outcome);
+                self.site.emit(outcome == Outcome::Resolved, || &self.message, &details);
+            }
+        }
+        #[cfg(not(feature = "full"))]
+        let _ = (outcome, &self.message);
+    }
+
+    #[cfg_attr(not(feature = "full"), allow(dead_code))]
+    fn details(&self, outcome: Outcome) -> Value {
+        let mut details = self.details.clone();
+        details.insert("outcome".to_owned(), outcome.as_str().into());
+        details.insert("steps".to_owned(), self.steps.load(Ordering::SeqCst).into());
+        details.insert("elapsed_ms".to_owned(), (self.started.elapsed().as_millis() as u64).into());
+        details.insert("deadline".to_owned(), self.deadline.to_json());
+        Value::Object(details)
+    }
+}
+
+// Obligations that have been created and not settled yet, reported by `lifecycle::shutdown()`.
+#[cfg(feature = "full")]
+static PENDING: Lazy<Mutex<BTreeMap<u64, Arc<State>>>> = Lazy::new(|| Mutex::new(BTreeMap::new()));
+
+#[cfg(feature = "full")]
+static NEXT_KEY: AtomicU64 = AtomicU64::new(0);
+
+/// A property that must be resolved before its [`Deadline`], created by [`assert_eventually!`](crate::assert_eventually).
+///
+/// The property passes if [`resolve`](Obligation::resolve) is called in time, and fails if the deadline passes first,
+/// if the obligation is dropped while pending, or if it is still pending at [`lifecycle::shutdown`](crate::lifecycle::shutdown).
+/// Use [`cancel`](Obligation::cancel) to withdraw an obligation whose precondition no longer holds.
+///
+/// The deadline is checked whenever the obligation is used. An obligation that is never touched again
+/// after its deadline only fails when it is dropped or at shutdown.
+pub struct Obligation {
+    state: Arc<State>,
+}
+
+impl Obligation {
+    #[doc(hidden)]
+    #[cfg(feature = "full")]
+    pub fn new<S: serde::Serialize + ?Sized>(site: AssertionSite, message: Cow<'static, str>, details: &S, deadline: Deadline) -> Self {
+        let key = NEXT_KEY.fetch_add(1, Ordering::SeqCst);
+        let state = Arc::new(State {
+            site,
+            key,
+            message,
+            details: details_map(details),
+            deadline,
+            started: Instant::now(),
+            steps: AtomicU64::new(0),
+            settled: AtomicBool::new(false),
+        });
+        PENDING.lock().unwrap_or_else(|e| e.into_inner()).insert(key, state.clone());
+        Obligation { state }
+    }
+
+    #[doc(hidden)]
+    #[cfg(not(feature = "full"))]
+    pub fn new<S: serde::Serialize + ?Sized>(message: Cow<'static, str>, _details: &S, deadline: Deadline) -> Self {
+        Obligation {
+            state: Arc::new(State {
+                message,
+                details: Map::new(),
+                deadline,
+                started: Instant::now(),
+                steps: AtomicU64::new(0),
+                settled: AtomicBool::new(false),
+            }),
+        }
+    }
+
+    /// Returns true until the obligation has been resolved, has expired or has been cancelled.
+    pub fn is_pending(&self) -> bool {
+        !self.state.settled.load(Ordering::SeqCst)
+    }
+
+    /// The number of steps taken so far.
+    pub fn steps(&self) -> u64 {
+        self.state.steps.load(Ordering::SeqCst)
+    }
+
+    /// Records one logical step without resolving the obligation.
+    /// The property fails if this reaches the deadline.
+    pub fn step(&self) {
+        if !self.is_pending() {
+            return;
+        }
+        self.state.steps.fetch_add(1, Ordering::SeqCst);
+        if self.state.expired() {
+            self.state.settle(Some(Outcome::Expired));
+        }
+    }
+
+    /// Resolves the obligation, which passes unless its deadline has already passed.
+    pub fn resolve(&self) {
+        let outcome = if self.state.expired() { Outcome::Expired } else { Outcome::Resolved };
+        self.state.settle(Some(outcome));
+    }
+
+    /// Resolves the obligation if ``condition`` is true, and otherwise records one step.
+    pub fn check(&self, condition: bool) {
+        if condition {
+            self.resolve();
+        } else {
+            self.step();
+        }
+    }
+
+    /// Withdraws the obligation without passing or failing it.
+    pub fn cancel(self) {
+        self.state.settle(None);
+    }
+}
+
+impl Drop for Obligation {
+    fn drop(&mut self) {
+        if self.state.expired() {
+            self.state.settle(Some(Outcome::Expired));
+        } else {
+            self.state.settle(Some(Outcome::Abandoned));
+        }
+    }
+}
+
+/// Fails every obligation that is still pending.
+#[cfg(feature = "full")]
+pub(crate) fn settle_pending() {
+    let pending: Vec<Arc<State>> = {
+        let mut pending = PENDING.lock().unwrap_or_else(|e| e.into_inner());
+        std::mem::take(&mut *pending).into_values().collect()
+    };
+    for state in pending {
+        state.settle(Some(Outcome::UnresolvedAtShutdown));
+    }
+}
@@ -1,26 +1,33 @@
 use std::sync::atomic::{self, AtomicI16, AtomicI32, AtomicI64, AtomicI8, AtomicIsize, AtomicU16, AtomicU32, AtomicU64, AtomicU8, AtomicUsize};
+use std::sync::Mutex;
+
+use once_cell::sync::{Lazy, OnceCell};
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 
-use once_cell::sync::Lazy;
-use serde::Serialize;
 use serde_json::{json, Value};
 
+use crate::assert::GuidanceDistance;
 use crate::internal;
+use crate::protocol::{Guidance, Location};
 
-use super::AntithesisLocationInfo;
+pub use crate::protocol::GuidanceType;
 
 // Types and traits that model the SDK filtering of numerical guidance reporting.
 // For assertions like "always (x < y)", we would like to only report the most extreme
-// violations seen so far, which is implemented by having a `Guard` that keep a maximizing
+// violations seen so far, which is implemented by keeping a maximizing (or minimizing)
 // watermark on the difference (x - y).
-// The `AtomicMinMax` trait requirement allows multiple concurrent update to the watermark.
-
-// NOTE: The structures setup in this modules allow `Guard` to be generic over the numeric
-// type (or even any partially ordered type).
-// But due to some limitation of stable Rust, we are only instanciating `Guard<f64>` by
-// converting the result of all `x - y` into `f64`.
-// See the impl `numeric_guidance_helper` for more details on the limitation.
-// Once that is lifted, some implementations of `Diff` can be changed to truly take advantage
-// of the zero-cost polymorphism that `Guard` provides.
+//
+// A static cannot be generic, and its type cannot be inferred, so the macros cannot
+// declare a watermark of the type returned by `Diff::diff`. Instead, every call site
+// declares a `Guards`, which has one watermark per family of distances, and the
+// `Distance` trait directs each distance to the watermark of its family:
+// - floats are compared as `f64`,
+// - integers of at most 32 bits have exact differences in an `i64`,
+// - 64 and 128-bit integers have exact differences in a `Wide`.
+// Only the watermark matching the operand types of a call site is ever used.
 pub struct Guard<const MAX: bool, T: AtomicMinMax> {
     mark: T::Atomic,
 }
@@ -57,10 +64,153 @@ impl<const MAX: bool, T: AtomicMinMax + PartialOrd + Copy> Guard<MAX, T> {
     }
 }
 
+/// The exact difference of two integers of up to 128 bits.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Wide {
+    negative: bool,
+    magnitude: u128,
+}
+
+impl Wide {
+    pub fn new(negative: bool, magnitude: u128) -> Self {
+        // Zero is never negative, so that it has a single representation
+        Self { negative: negative && magnitude != 0, magnitude }
+    }
+
+    // The closest `i64`, and whether it is exact.
+    fn saturate(self) -> (i64, bool) {
+        match i64::try_from(self.magnitude) {
+            Ok(magnitude) if self.negative => (-magnitude, true),
+            Ok(magnitude) => (magnitude, true),
+            Err(_) if self.negative && self.magnitude == 1 << 63 => (i64::MIN, true),
+            Err(_) if self.negative => (i64::MIN, false),
+            Err(_) => (i64::MAX, false),
+        }
+    }
+}
+
+impl Ord for Wide {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl PartialOrd for Wide {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// There is no 128-bit atomic on stable Rust, so wide watermarks are kept behind a lock.
+// A copy of the watermark saturated to an `i64` is kept next to it, so that most
+// distances that do not exceed the watermark are rejected without taking the lock.
+// The copy is only stored after the watermark, and both only ever move outwards, so
+// a stale copy at worst sends a distance to the lock.
+pub struct WideGuard<const MAX: bool> {
+    mark: OnceCell<Mutex<Option<Wide>>>,
+    hint: AtomicI64,
+}
+
+impl<const MAX: bool> WideGuard<MAX> {
+    const UNSET: i64 = if MAX { i64::MIN } else { i64::MAX };
+
+    pub const fn init() -> Self {
+        Self { mark: OnceCell::new(), hint: AtomicI64::new(Self::UNSET) }
+    }
+
+    pub fn should_emit(&self, new: Wide) -> bool {
+        let (near, exact) = new.saturate();
+        let hint = self.hint.load(atomic::Ordering::Relaxed);
+        let reach = if MAX { near.cmp(&hint) } else { hint.cmp(&near) };
+        if reach == Ordering::Less || (reach == Ordering::Equal && exact && hint != Self::UNSET) {
+            return false;
+        }
+        let mut mark = self.mark.get_or_init(|| Mutex::new(None)).lock().unwrap_or_else(|e| e.into_inner());
+        let exceeds = match *mark {
+            None => true,
+            Some(old) if MAX => new > old,
+            Some(old) => new < old,
+        };
+        if exceeds {
+            *mark = Some(new);
+            self.hint.store(near, atomic::Ordering::Relaxed);
+        }
+        exceeds
+    }
+}
+
+/// The watermarks of a numeric guidance call site.
+pub struct Guards<const MAX: bool> {
+    float: Guard<MAX, f64>,
+    narrow: Guard<MAX, i64>,
+    wide: WideGuard<MAX>,
+}
+
+impl<const MAX: bool> Guards<MAX> {
+    pub const fn init() -> Self {
+        Self {
+            float: Guard::init(),
+            narrow: Guard::init(),
+            wide: WideGuard::init(),
+        }
+    }
+
+    pub fn should_emit<D: Distance>(&self, distance: D) -> bool {
+        distance.should_emit(self)
+    }
+}
+
+/// A difference between two operands of a numeric guidance, which knows
+/// which watermark of [`Guards`] tracks it.
+pub trait Distance: Copy {
+    fn should_emit<const MAX: bool>(self, guards: &Guards<MAX>) -> bool;
+}
+
+impl Distance for f64 {
+    fn should_emit<const MAX: bool>(self, guards: &Guards<MAX>) -> bool {
+        guards.float.should_emit(self)
+    }
+}
+
+impl Distance for i64 {
+    fn should_emit<const MAX: bool>(self, guards: &Guards<MAX>) -> bool {
+        guards.narrow.should_emit(self)
+    }
+}
+
+impl Distance for Wide {
+    fn should_emit<const MAX: bool>(self, guards: &Guards<MAX>) -> bool {
+        guards.wide.should_emit(self)
+    }
+}
+
 pub trait Diff {
-    type Output;
+    type Output: Distance;
+
+    fn diff(&self, other: &Self) -> Self::Output;
 
-    fn diff(&self, other: Self) -> Self::Output;
+    /// The JSON representation of an operand in the guidance data and the assertion details.
+    fn to_json(&self) -> Value;
+}
+
+impl<T: GuidanceDistance> Diff for T
+where
+    T::Measure: Diff,
+{
+    type Output = <T::Measure as Diff>::Output;
+
+    fn diff(&self, other: &Self) -> Self::Output {
+        self.measure().diff(&other.measure())
+    }
+
+    fn to_json(&self) -> Value {
+        self.measure().to_json()
+    }
 }
 
 macro_rules! impl_extremal {
@@ -140,51 +290,93 @@ macro_rules! impl_atomic_min_max_float {
 
 impl_atomic_min_max_float! { (f32, AtomicF32, AtomicU32) (f64, AtomicF64, AtomicU64)}
 
-macro_rules! impl_diff_unsigned {
+// Integers of at most 32 bits: the difference always fits in an `i64`.
+macro_rules! impl_diff_narrow {
     ($($t:ty)*) => {$(
         impl Diff for $t {
-            type Output = f64;
+            type Output = i64;
+
+            fn diff(&self, other: &Self) -> Self::Output {
+                i64::from(*self) - i64::from(*other)
+            }
+
+            fn to_json(&self) -> Value {
+                Value::from(*self)
+            }
+        }
+    )*};
+}
+
+impl_diff_narrow! { u8 u16 u32 i8 i16 i32 }
 
-            fn diff(&self, other: Self) -> Self::Output {
-                if *self < other {
-                    -((other - self) as f64)
+macro_rules! impl_diff_unsigned_wide {
+    ($($t:ty)*) => {$(
+        impl Diff for $t {
+            type Output = Wide;
+
+            fn diff(&self, other: &Self) -> Self::Output {
+                if self < other {
+                    Wide::new(true, (other - self) as u128)
                 } else {
-                    (self - other) as f64
+                    Wide::new(false, (self - other) as u128)
                 }
             }
+
+            fn to_json(&self) -> Value {
+                wide_json(*self)
+            }
         }
     )*};
 }
 
-impl_diff_unsigned! { usize u8 u16 u32 u64 u128 }
+impl_diff_unsigned_wide! { usize u64 u128 }
 
-macro_rules! impl_diff_signed {
+macro_rules! impl_diff_signed_wide {
     ($(($t:ty, $unsigned_t:ty))*) => {$(
         impl Diff for $t {
-            type Output = f64;
+            type Output = Wide;
 
-            fn diff(&self, other: Self) -> Self::Output {
-                if *self < other {
-                    // For correctness, see
-                    // https://github.com/rust-lang/rust/blob/11e760b7f4e4aaa11bf51a64d4bb7f1171f6e466/library/core/src/num/int_macros.rs#L3443-L3456
-                    -((other as $unsigned_t).wrapping_sub(*self as $unsigned_t) as f64)
+            fn diff(&self, other: &Self) -> Self::Output {
+                // For correctness, see
+                // https://github.com/rust-lang/rust/blob/11e760b7f4e4aaa11bf51a64d4bb7f1171f6e466/library/core/src/num/int_macros.rs#L3443-L3456
+                if self < other {
+                    Wide::new(true, (*other as $unsigned_t).wrapping_sub(*self as $unsigned_t) as u128)
                 } else {
-                    (*self as $unsigned_t).wrapping_sub(other as $unsigned_t) as f64
+                    Wide::new(false, (*self as $unsigned_t).wrapping_sub(*other as $unsigned_t) as u128)
                 }
             }
+
+            fn to_json(&self) -> Value {
+                wide_json(*self)
+            }
         }
     )*};
 }
 
-impl_diff_signed! { (isize, usize) (i8, u8) (i16, u16) (i32, u32) (i64, u64) (i128, u128) }
+impl_diff_signed_wide! { (isize, usize) (i64, u64) (i128, u128) }
+
+// JSON numbers are 64-bit at most; larger values are kept exact as strings.
+fn wide_json<T: TryInto<i64> + TryInto<u64> + ToString + Copy>(value: T) -> Value {
+    if let Ok(v) = TryInto::<i64>::try_into(value) {
+        Value::from(v)
+    } else if let Ok(v) = TryInto::<u64>::try_into(value) {
+        Value::from(v)
+    } else {
+        Value::String(value.to_string())
+    }
+}
 
 macro_rules! impl_diff_float {
     ($($t:ty)*) => {$(
         impl Diff for $t {
             type Output = f64;
 
-            fn diff(&self, other: Self) -> Self::Output {
-                (self - other) as f64
+            fn diff(&self, other: &Self) -> Self::Output {
+                f64::from(*self) - f64::from(*other)
+            }
+
+            fn to_json(&self) -> Value {
+                Value::from(*self)
             }
         }
     )*};
@@ -192,23 +384,30 @@ macro_rules! impl_diff_float {
 
 impl_diff_float! { f32 f64 }
 
-#[derive(Copy, Clone, Serialize)]
-#[serde(rename_all(serialize = "lowercase"))]
-pub enum GuidanceType {
-    Numeric,
-    Boolean,
-    Json,
+// Filters JSON guidance so that a callsite only reports a state that differs
+// from the last one it reported. States are compared by the hash of their
+// serialization, with the low bit set so that 0 can mean "nothing reported yet".
+pub struct StateFilter {
+    last: AtomicU64,
 }
 
-#[derive(Serialize)]
-struct GuidanceInfo<'a> {
-    guidance_type: GuidanceType,
-    message: &'a str,
-    id: &'a str,
-    location: AntithesisLocationInfo<'a>,
-    maximize: bool,
-    guidance_data: Value,
-    hit: bool,
+impl StateFilter {
+    pub const fn new() -> Self {
+        Self { last: AtomicU64::new(0) }
+    }
+
+    pub fn should_emit(&self, state: &Value) -> bool {
+        let mut hasher = DefaultHasher::new();
+        state.to_string().hash(&mut hasher);
+        let hash = hasher.finish() | 1;
+        self.last.swap(hash, atomic::Ordering::SeqCst) != hash
+    }
+}
+
+impl Default for StateFilter {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 pub struct GuidanceCatalogInfo {
@@ -223,6 +422,32 @@ pub struct GuidanceCatalogInfo {
     pub maximize: bool,
 }
 
+impl GuidanceCatalogInfo {
+    fn to_record(&self) -> Guidance<'static> {
+        let location = Location {
+            class: Cow::Borrowed(self.class),
+            function: Cow::Borrowed(*Lazy::force(self.function)),
+            file: Cow::Borrowed(self.file),
+            begin_line: self.begin_line,
+            begin_column: self.begin_column,
+        };
+        Guidance {
+            guidance_type: self.guidance_type,
+            message: Cow::Borrowed(self.message),
+            id: Cow::Borrowed(self.id),
+            location,
+            maximize: self.maximize,
+            guidance_data: Value::Null,
+            hit: false,
+        }
+    }
+}
+
+/// Returns the catalog entry of every guidance compiled into the program.
+pub fn catalog() -> impl Iterator<Item = Guidance<'static>> {
+    super::ANTITHESIS_GUIDANCE_CATALOG.iter().map(GuidanceCatalogInfo::to_record)
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn guidance_impl<'a>(
     guidance_type: GuidanceType,
@@ -237,10 +462,95 @@ pub fn guidance_impl<'a>(
     guidance_data: Value,
     hit: bool,
 ) {
-    let location = AntithesisLocationInfo { class, function, file, begin_line, begin_column };
-    let guidance = GuidanceInfo {
-        guidance_type, message, id, location, maximize, guidance_data, hit
+    let location = Location {
+        class: Cow::Borrowed(class),
+        function: Cow::Borrowed(function),
+        file: Cow::Borrowed(file),
+        begin_line,
+        begin_column,
+    };
+    let guidance = Guidance {
+        guidance_type,
+        message: Cow::Borrowed(message),
+        id: Cow::Borrowed(id),
+        location,
+        maximize,
+        guidance_data,
+        hit,
     };
 
     internal::dispatch_output(&json!({ "antithesis_guidance": guidance }));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wide_ordering() {
+        let values = [
+            Wide::new(true, u128::MAX),
+            Wide::new(true, 2),
+            Wide::new(true, 0),
+            Wide::new(false, 1),
+            Wide::new(false, u128::MAX),
+        ];
+        assert_eq!(values[2], Wide::new(false, 0));
+        for pair in values.windows(2) {
+            assert!(pair[0] < pair[1]);
+        }
+    }
+
+    #[test]
+    fn exact_large_differences() {
+        let base: u64 = 1 << 53;
+        assert_eq!((base + 1).diff(&base), Wide::new(false, 1));
+        assert_eq!(i64::MIN.diff(&i64::MAX), Wide::new(true, u64::MAX as u128));
+        assert_eq!(i128::MAX.diff(&i128::MIN), Wide::new(false, u128::MAX));
+        assert_eq!(0u128.diff(&u128::MAX), Wide::new(true, u128::MAX));
+        assert_eq!(3u8.diff(&200), -197i64);
+    }
+
+    #[test]
+    fn guards_watermarks() {
+        let guards: Guards<true> = Guards::init();
+        let base: u64 = 1 << 53;
+        assert!(guards.should_emit((base + 1).diff(&base)));
+        assert!(!guards.should_emit((base + 1).diff(&base)));
+        assert!(guards.should_emit((base + 2).diff(&base)));
+        assert!(guards.should_emit(1i64));
+        assert!(guards.should_emit(0.0f64.diff(&0.0)));
+        assert!(!guards.should_emit(-1.0f64));
+
+        let guards: Guards<false> = Guards::init();
+        assert!(guards.should_emit(u128::MAX.diff(&0)));
+        assert!(guards.should_emit(0u128.diff(&1)));
+        assert!(!guards.should_emit(0u128.diff(&0)));
+    }
+
+    #[test]
+    fn wide_guard_hint() {
+        let guard: WideGuard<true> = WideGuard::init();
+        assert!(guard.should_emit(Wide::new(true, 1 << 63)));
+        assert!(!guard.should_emit(Wide::new(true, 1 << 63)));
+        assert!(guard.should_emit(Wide::new(false, u64::MAX as u128)));
+        assert!(guard.should_emit(Wide::new(false, u128::MAX)));
+        assert!(!guard.should_emit(Wide::new(false, u64::MAX as u128)));
+        assert!(!guard.should_emit(Wide::new(false, u128::MAX)));
+
+        let guard: WideGuard<false> = WideGuard::init();
+        assert!(guard.should_emit(Wide::new(false, i64::MAX as u128)));
+        assert!(!guard.should_emit(Wide::new(false, i64::MAX as u128)));
+        assert!(guard.should_emit(Wide::new(true, u128::MAX)));
+        assert!(!guard.should_emit(Wide::new(true, 1)));
+    }
+
+    #[test]
+    fn operands_json() {
+        assert_eq!(u128::MAX.to_json(), json!(u128::MAX.to_string()));
+        assert_eq!(i128::MIN.to_json(), json!(i128::MIN.to_string()));
+        assert_eq!((u64::MAX as u128).to_json(), json!(u64::MAX));
+        assert_eq!((-5i128).to_json(), json!(-5));
+        assert_eq!(1.5f32.to_json(), json!(1.5));
+    }
+}
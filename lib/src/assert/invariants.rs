@@ -0,0 +1,47 @@
+use serde::{Serialize, Serializer};
+use serde_json::{json, Value};
+
+/// Wraps a value whose invariants are checked by `#[derive(AntithesisInvariants)]`,
+/// to add it to the assertion details when it can be serialized. Calling
+/// ``(&&Snapshot(value)).snapshot()`` picks [`SerializeSnapshot`] for values
+/// implementing ``Serialize``, and [`NoSnapshot`] for any other value.
+pub struct Snapshot<'a, T: ?Sized>(pub &'a T);
+
+/// The details of the invariants of a value, as picked by [`Snapshot`]. The value
+/// is only serialized when the details are, which only happens for emitted hits.
+pub struct SnapshotDetails<'a, T: ?Sized> {
+    value: &'a T,
+    serialize: fn(&T) -> Value,
+}
+
+impl<T: ?Sized> Serialize for SnapshotDetails<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.serialize)(self.value).serialize(serializer)
+    }
+}
+
+pub trait SerializeSnapshot<'a, T: ?Sized> {
+    fn snapshot(&self) -> SnapshotDetails<'a, T>;
+}
+
+impl<'a, T: Serialize + ?Sized> SerializeSnapshot<'a, T> for &Snapshot<'a, T> {
+    fn snapshot(&self) -> SnapshotDetails<'a, T> {
+        SnapshotDetails {
+            value: self.0,
+            serialize: |value| serde_json::to_value(value).unwrap_or(Value::Null),
+        }
+    }
+}
+
+pub trait NoSnapshot<'a, T: ?Sized> {
+    fn snapshot(&self) -> SnapshotDetails<'a, T>;
+}
+
+impl<'a, T: ?Sized> NoSnapshot<'a, T> for Snapshot<'a, T> {
+    fn snapshot(&self) -> SnapshotDetails<'a, T> {
+        SnapshotDetails {
+            value: self.0,
+            serialize: |_| json!({}),
+        }
+    }
+}
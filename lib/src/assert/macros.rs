@@ -26,6 +26,64 @@ macro_rules! function {
                 &name[..name.len() - 4]
             });
     };
+    // The name of the function is known, for example from an attribute macro
+    ($static:ident = $name:expr) => {
+        use $crate::once_cell::sync::Lazy;
+        static $static: $crate::once_cell::sync::Lazy<&'static str> =
+            $crate::once_cell::sync::Lazy::new(|| $name);
+    };
+}
+
+/// The static part of an assertion message, used in the catalog and as the default ``id``.
+///
+/// A message is either a string literal, or a parenthesized format string and its arguments.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! message_template {
+    ($message:literal) => {
+        $message
+    };
+    (($template:literal $(, $arg:expr)* $(,)?)) => {
+        $template
+    };
+    ($($rest:tt)*) => {
+        ::std::compile_error!(
+r#"Invalid assertion message.
+Expected a string literal, or a format string and its arguments:
+    `"assertion message (static literal)"`
+    `("assertion message {} (format string)", arg_expr)`
+"#
+        )
+    };
+}
+
+/// The message of an assertion as emitted, rendering the format string if any.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! message_render {
+    ($message:literal) => {
+        ::std::borrow::Cow::<'static, str>::Borrowed($message)
+    };
+    (($template:literal $(, $arg:expr)* $(,)?)) => {
+        ::std::borrow::Cow::<'static, str>::Owned(::std::format!($template $(, $arg)*))
+    };
+    ($($rest:tt)*) => {
+        $crate::message_template!($($rest)*)
+    };
+}
+
+/// The message of an assertion as a `&LazyMessage`, formatted when it is first emitted.
+/// A message that was already made lazy, by a macro emitting several records, can be passed along.
+#[cfg(feature = "full")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! lazy_message {
+    ($message:tt) => {
+        &$crate::assert::LazyMessage::new(|| $crate::message_render!($message))
+    };
+    ($message:tt, $lazy:expr) => {
+        $lazy
+    };
 }
 
 /// Common handling used by all the assertion-related macros
@@ -35,13 +93,14 @@ macro_rules! function {
 macro_rules! assert_helper {
     // The handling of this pattern-arm of assert_helper
     // is wrapped in a block {} to avoid name collisions
-    (condition = $condition:expr, $message:literal, $(details = $details:expr)?, $assert_type:path, $display_type:literal, must_hit = $must_hit:literal) => {{
+    ($(function = $function:expr,)? $(message = $lazy:expr,)? condition = $condition:expr, id = $id:expr, $message:tt, $(details = $details:expr)?, $assert_type:path, $display_type:literal, must_hit = $must_hit:literal) => {{
         // Force evaluation of expressions.
         let condition = $condition;
         let details = &$crate::serde_json::json!({});
         $(let details = $details;)?
+        let message = $crate::lazy_message!($message $(, $lazy)?);
 
-        $crate::function!(FUN_NAME);
+        $crate::function!(FUN_NAME $(= $function)?);
 
         use $crate::assert::AssertionCatalogInfo;
         #[$crate::linkme::distributed_slice($crate::assert::ANTITHESIS_CATALOG)]
@@ -50,36 +109,39 @@ macro_rules! assert_helper {
             assert_type: $assert_type,
             display_type: $display_type,
             condition: false,
-            message: $message,
+            message: $crate::message_template!($message),
             class: ::std::module_path!(),
             function: &FUN_NAME, /* function: &Lazy<&str> */
             file: ::std::file!(),
             begin_line: ::std::line!(),
             begin_column: ::std::column!(),
             must_hit: $must_hit,
-            id: $message,
+            id: $id,
         };
 
-        let ptr_function = Lazy::force(&FUN_NAME);
-
         static TRACKER: $crate::assert::TrackingInfo = $crate::assert::TrackingInfo::new();
 
-        $crate::assert::assert_impl(
-            $assert_type,                     /* assert_type */
-            $display_type,                    /* display_type */
-            condition,                        /* condition */
-            $message,                         /* message */
-            ::std::module_path!(),            /* class */
-            *ptr_function,                    /* function */
-            ::std::file!(),                   /* file */
-            ::std::line!(),                   /* line */
-            ::std::column!(),                 /* column */
-            true,                             /* hit */
-            $must_hit,                        /* must-hit */
-            $message,                         /* id */
-            details,                          /* details */
-            Some(&TRACKER),                   /* tracker */
-        )
+        // Only the hits that are emitted pay for rendering the message
+        if TRACKER.track(condition) {
+            let ptr_function = Lazy::force(&FUN_NAME);
+
+            $crate::assert::assert_impl(
+                $assert_type,                     /* assert_type */
+                $display_type,                    /* display_type */
+                condition,                        /* condition */
+                message.get(),                    /* message */
+                ::std::module_path!(),            /* class */
+                *ptr_function,                    /* function */
+                ::std::file!(),                   /* file */
+                ::std::line!(),                   /* line */
+                ::std::column!(),                 /* column */
+                true,                             /* hit */
+                $must_hit,                        /* must-hit */
+                $id,                              /* id */
+                details,                          /* details */
+                None,                             /* tracker, already updated */
+            )
+        }
     }}; // end pattern-arm block
 }
 
@@ -87,16 +149,51 @@ macro_rules! assert_helper {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! assert_helper {
-    (condition = $condition:expr, $message:literal, $(details = $details:expr)?, $assert_type:path, $display_type:literal, must_hit = $must_hit:literal) => {{
+    ($(function = $function:expr,)? $(message = $lazy:expr,)? condition = $condition:expr, id = $id:expr, $message:tt, $(details = $details:expr)?, $assert_type:path, $display_type:literal, must_hit = $must_hit:literal) => {{
         // Force evaluation of expressions, ensuring that
         // any side effects of these expressions will always be
         // evaluated at runtime - even if the assertion itself
         // is supressed by the `no-antithesis-sdk` feature
         let condition = $condition;
+        let _message = || $crate::message_render!($message);
         $(let details = $details;)?
     }};
 }
 
+/// Declares the catalog entry and tracker of an assertion call site, and evaluates to its `AssertionSite`.
+#[cfg(feature = "full")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! assertion_site {
+    (id = $id:expr, $message:tt, $assert_type:path, $display_type:literal, must_hit = $must_hit:literal) => {{
+        $crate::function!(FUN_NAME);
+
+        use $crate::assert::AssertionCatalogInfo;
+        #[$crate::linkme::distributed_slice($crate::assert::ANTITHESIS_CATALOG)]
+        #[linkme(crate = $crate::linkme)] // Refer to our re-exported linkme.
+        static CATALOG_ITEM: AssertionCatalogInfo = AssertionCatalogInfo {
+            assert_type: $assert_type,
+            display_type: $display_type,
+            condition: false,
+            message: $crate::message_template!($message),
+            class: ::std::module_path!(),
+            function: &FUN_NAME, /* function: &Lazy<&str> */
+            file: ::std::file!(),
+            begin_line: ::std::line!(),
+            begin_column: ::std::column!(),
+            must_hit: $must_hit,
+            id: $id,
+        };
+
+        static TRACKER: $crate::assert::TrackingInfo = $crate::assert::TrackingInfo::new();
+
+        $crate::assert::AssertionSite {
+            info: &CATALOG_ITEM,
+            tracker: &TRACKER,
+        }
+    }};
+}
+
 /// Assert that ``condition`` is true every time this function is called, **and** that it is
 /// called at least once. The corresponding test property will be viewable in the ``Antithesis SDK: Always`` group of your triage report.
 ///
@@ -113,9 +210,21 @@ macro_rules! assert_helper {
 /// ```
 #[macro_export]
 macro_rules! assert_always {
-    ($condition:expr, $message:literal$(, $details:expr)?) => {
+    (id = $id:literal, $condition:expr, $message:tt$(, $details:expr)?) => {
         $crate::assert_helper!(
             condition = $condition,
+            id = $id,
+            $message,
+            $(details = $details)?,
+            $crate::assert::AssertType::Always,
+            "Always",
+            must_hit = true
+        )
+    };
+    ($condition:expr, $message:tt$(, $details:expr)?) => {
+        $crate::assert_helper!(
+            condition = $condition,
+            id = $crate::message_template!($message),
             $message,
             $(details = $details)?,
             $crate::assert::AssertType::Always,
@@ -128,6 +237,7 @@ macro_rules! assert_always {
 r#"Invalid syntax when calling macro `assert_always`.
 Example usage:
     `assert_always!(condition_expr, "assertion message (static literal)", &details_json_value_expr)`
+    `assert_always!(id = "stable id (static literal)", condition_expr, "assertion message (static literal)", &details_json_value_expr)`
 "#
         );
     };
@@ -149,137 +259,918 @@ Example usage:
 /// ```
 #[macro_export]
 macro_rules! assert_always_or_unreachable {
-    ($condition:expr, $message:literal$(, $details:expr)?) => {
+    (id = $id:literal, $condition:expr, $message:tt$(, $details:expr)?) => {
+        $crate::assert_helper!(
+            condition = $condition,
+            id = $id,
+            $message,
+            $(details = $details)?,
+            $crate::assert::AssertType::Always,
+            "AlwaysOrUnreachable",
+            must_hit = false
+        )
+    };
+    ($condition:expr, $message:tt$(, $details:expr)?) => {
+        $crate::assert_helper!(
+            condition = $condition,
+            id = $crate::message_template!($message),
+            $message,
+            $(details = $details)?,
+            $crate::assert::AssertType::Always,
+            "AlwaysOrUnreachable",
+            must_hit = false
+        )
+    };
+    ($($rest:tt)*) => {
+        ::std::compile_error!(
+r#"Invalid syntax when calling macro `assert_always_or_unreachable`.
+Example usage:
+    `assert_always_or_unreachable!(condition_expr, "assertion message (static literal)", &details_json_value_expr)`
+    `assert_always_or_unreachable!(id = "stable id (static literal)", condition_expr, "assertion message (static literal)", &details_json_value_expr)`
+"#
+        );
+    };
+}
+
+/// Assert that ``condition`` is true at least one time that this function was called.
+/// (If the assertion is never encountered, the test property will therefore fail.)
+/// This test property will be viewable in the ``Antithesis SDK: Sometimes`` group.
+///
+/// # Example
+///
+/// ```
+/// use serde_json::{json};
+/// use antithesis_sdk::{assert_sometimes, random};
+///
+/// const MAX_ALLOWED: u64 = 100;
+/// let actual = random::get_random() % 120u64;
+/// let details = json!({"max_allowed": MAX_ALLOWED, "actual": actual});
+/// antithesis_sdk::assert_sometimes!(actual > MAX_ALLOWED, "Value in range", &details);
+/// ```
+#[macro_export]
+macro_rules! assert_sometimes {
+    (id = $id:literal, $condition:expr, $message:tt$(, $details:expr)?) => {
         $crate::assert_helper!(
             condition = $condition,
+            id = $id,
+            $message,
+            $(details = $details)?,
+            $crate::assert::AssertType::Sometimes,
+            "Sometimes",
+            must_hit = true
+        )
+    };
+    ($condition:expr, $message:tt$(, $details:expr)?) => {
+        $crate::assert_helper!(
+            condition = $condition,
+            id = $crate::message_template!($message),
+            $message,
+            $(details = $details)?,
+            $crate::assert::AssertType::Sometimes,
+            "Sometimes",
+            must_hit = true
+        )
+    };
+    ($($rest:tt)*) => {
+        ::std::compile_error!(
+r#"Invalid syntax when calling macro `assert_sometimes`.
+Example usage:
+    `assert_sometimes!(condition_expr, "assertion message (static literal)", &details_json_value_expr)`
+    `assert_sometimes!(id = "stable id (static literal)", condition_expr, "assertion message (static literal)", &details_json_value_expr)`
+"#
+        );
+    };
+}
+
+/// Assert that a line of code is reached at least once.
+/// The corresponding test property will pass if this macro is ever called. (If it is never called the test property will therefore fail.)
+/// This test property will be viewable in the ``Antithesis SDK: Reachablity assertions`` group.
+///
+/// # Example
+///
+/// ```
+/// use serde_json::{json};
+/// use antithesis_sdk::{assert_reachable, random};
+///
+/// const MAX_ALLOWED: u64 = 100;
+/// let actual = random::get_random() % 120u64;
+/// let details = json!({"max_allowed": MAX_ALLOWED, "actual": actual});
+/// if (actual > MAX_ALLOWED) {
+///     antithesis_sdk::assert_reachable!("Value in range", &details);
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_reachable {
+    (id = $id:literal, $message:tt$(, $details:expr)?) => {
+        $crate::assert_helper!(
+            condition = true,
+            id = $id,
+            $message,
+            $(details = $details)?,
+            $crate::assert::AssertType::Reachability,
+            "Reachable",
+            must_hit = true
+        )
+    };
+    ($message:tt$(, $details:expr)?) => {
+        $crate::assert_helper!(
+            condition = true,
+            id = $crate::message_template!($message),
+            $message,
+            $(details = $details)?,
+            $crate::assert::AssertType::Reachability,
+            "Reachable",
+            must_hit = true
+        )
+    };
+    ($($rest:tt)*) => {
+        ::std::compile_error!(
+r#"Invalid syntax when calling macro `assert_reachable`.
+Example usage:
+    `assert_reachable!("assertion message (static literal)", &details_json_value_expr)`
+    `assert_reachable!(id = "stable id (static literal)", "assertion message (static literal)", &details_json_value_expr)`
+"#
+        );
+    };
+}
+
+/// Assert that a line of code is never reached.
+/// The corresponding test property will fail if this macro is ever called.
+/// (If it is never called the test property will therefore pass.)
+/// This test property will be viewable in the ``Antithesis SDK: Reachablity assertions`` group.
+///
+/// # Example
+///
+/// ```
+/// use serde_json::{json};
+/// use antithesis_sdk::{assert_unreachable, random};
+///
+/// const MAX_ALLOWED: u64 = 100;
+/// let actual = random::get_random() % 120u64;
+/// let details = json!({"max_allowed": MAX_ALLOWED, "actual": actual});
+/// if (actual > 120u64) {
+///     antithesis_sdk::assert_unreachable!("Value is above range", &details);
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_unreachable {
+    (id = $id:literal, $message:tt$(, $details:expr)?) => {
+        $crate::assert_helper!(
+            condition = false,
+            id = $id,
+            $message,
+            $(details = $details)?,
+            $crate::assert::AssertType::Reachability,
+            "Unreachable",
+            must_hit = false
+        )
+    };
+    ($message:tt$(, $details:expr)?) => {
+        $crate::assert_helper!(
+            condition = false,
+            id = $crate::message_template!($message),
+            $message,
+            $(details = $details)?,
+            $crate::assert::AssertType::Reachability,
+            "Unreachable",
+            must_hit = false
+        )
+    };
+    ($($rest:tt)*) => {
+        ::std::compile_error!(
+r#"Invalid syntax when calling macro `assert_unreachable`.
+Example usage:
+    `assert_unreachable!("assertion message (static literal)", &details_json_value_expr)`
+    `assert_unreachable!(id = "stable id (static literal)", "assertion message (static literal)", &details_json_value_expr)`
+"#
+        );
+    };
+}
+
+#[cfg(feature = "full")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! result_helper {
+    ($(function = $function:expr,)? id = $id:expr, expect_ok = $expect_ok:literal, $result:expr, $message:tt, $(details = $details:expr)?, $assert_type:path, $display_type:literal, must_hit = $must_hit:literal) => {{
+        #[allow(unused_imports)]
+        use $crate::assert::outcome::{DebugCapture as _, DerefErrorCapture as _, ErrorCapture as _};
+        let result = $result;
+        let details = &();
+        $(let details = $details;)?
+        // The error is only described if the hit is emitted
+        let details = $crate::assert::outcome::ResultDetails {
+            details,
+            capture: result
+                .as_ref()
+                .err()
+                .map(|error| move || (&&&$crate::assert::outcome::Captured(error)).capture()),
+        };
+        $crate::assert_helper!(
+            $(function = $function,)?
+            condition = result.is_ok() == $expect_ok,
+            id = $id,
+            $message,
+            details = &details,
+            $assert_type,
+            $display_type,
+            must_hit = $must_hit
+        );
+        result
+    }};
+}
+
+#[cfg(not(feature = "full"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! result_helper {
+    ($(function = $function:expr,)? id = $id:expr, expect_ok = $expect_ok:literal, $result:expr, $message:tt, $(details = $details:expr)?, $assert_type:path, $display_type:literal, must_hit = $must_hit:literal) => {{
+        let result = $result;
+        let _message = || $crate::message_render!($message);
+        $(let details = $details;)?
+        result
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! option_helper {
+    (id = $id:expr, expect_some = $expect_some:literal, $option:expr, $message:tt, $(details = $details:expr)?, $assert_type:path, $display_type:literal, must_hit = $must_hit:literal) => {{
+        let option = $option;
+        $crate::assert_helper!(
+            condition = option.is_some() == $expect_some,
+            id = $id,
+            $message,
+            $(details = $details)?,
+            $assert_type,
+            $display_type,
+            must_hit = $must_hit
+        );
+        option
+    }};
+}
+
+/// Assert that ``result`` is ``Ok`` every time this function is called, **and** that it is
+/// called at least once, then evaluate to ``result``. When it is an ``Err``, the error is added to the
+/// details under ``error``: its ``Display``, its ``Debug`` and the ``Display`` of its ``source()`` chain if it
+/// implements ``Error``, or only its ``Debug`` otherwise.
+/// This test property will be viewable in the ``Antithesis SDK: Always`` group of your triage report.
+///
+/// # Example
+///
+/// ```
+/// use serde_json::json;
+/// use antithesis_sdk::assert_always_ok;
+///
+/// let port: u16 = assert_always_ok!("8080".parse::<u16>(), "Configured port is valid", &json!({"source": "env"})).unwrap_or(80);
+/// ```
+#[macro_export]
+macro_rules! assert_always_ok {
+    (id = $id:literal, $result:expr, $message:tt$(, $details:expr)?) => {
+        $crate::result_helper!(
+            id = $id,
+            expect_ok = true,
+            $result,
+            $message,
+            $(details = $details)?,
+            $crate::assert::AssertType::Always,
+            "Always",
+            must_hit = true
+        )
+    };
+    ($result:expr, $message:tt$(, $details:expr)?) => {
+        $crate::result_helper!(
+            id = $crate::message_template!($message),
+            expect_ok = true,
+            $result,
+            $message,
+            $(details = $details)?,
+            $crate::assert::AssertType::Always,
+            "Always",
+            must_hit = true
+        )
+    };
+    ($($rest:tt)*) => {
+        ::std::compile_error!(
+r#"Invalid syntax when calling macro `assert_always_ok`.
+Example usage:
+    `assert_always_ok!(result_expr, "assertion message (static literal)", &details_json_value_expr)`
+    `assert_always_ok!(id = "stable id (static literal)", result_expr, "assertion message (static literal)", &details_json_value_expr)`
+"#
+        );
+    };
+}
+
+/// Assert that ``result`` is ``Ok`` at least one time that this function was called, then evaluate to ``result``.
+/// When it is an ``Err``, the error is added to the details as in [`assert_always_ok!`](crate::assert_always_ok).
+/// This test property will be viewable in the ``Antithesis SDK: Sometimes`` group.
+///
+/// # Example
+///
+/// ```
+/// use antithesis_sdk::assert_sometimes_ok;
+///
+/// let attempt = std::fs::read_to_string("/etc/hostname");
+/// let hostname = assert_sometimes_ok!(attempt, "Hostname can be read").unwrap_or_default();
+/// ```
+#[macro_export]
+macro_rules! assert_sometimes_ok {
+    (id = $id:literal, $result:expr, $message:tt$(, $details:expr)?) => {
+        $crate::result_helper!(
+            id = $id,
+            expect_ok = true,
+            $result,
+            $message,
+            $(details = $details)?,
+            $crate::assert::AssertType::Sometimes,
+            "Sometimes",
+            must_hit = true
+        )
+    };
+    ($result:expr, $message:tt$(, $details:expr)?) => {
+        $crate::result_helper!(
+            id = $crate::message_template!($message),
+            expect_ok = true,
+            $result,
+            $message,
+            $(details = $details)?,
+            $crate::assert::AssertType::Sometimes,
+            "Sometimes",
+            must_hit = true
+        )
+    };
+    ($($rest:tt)*) => {
+        ::std::compile_error!(
+r#"Invalid syntax when calling macro `assert_sometimes_ok`.
+Example usage:
+    `assert_sometimes_ok!(result_expr, "assertion message (static literal)", &details_json_value_expr)`
+    `assert_sometimes_ok!(id = "stable id (static literal)", result_expr, "assertion message (static literal)", &details_json_value_expr)`
+"#
+        );
+    };
+}
+
+/// Assert that ``result`` is ``Err`` at least one time that this function was called, then evaluate to ``result``.
+/// When it is an ``Err``, the error is added to the details as in [`assert_always_ok!`](crate::assert_always_ok).
+/// This test property will be viewable in the ``Antithesis SDK: Sometimes`` group.
+///
+/// # Example
+///
+/// ```
+/// use antithesis_sdk::assert_sometimes_err;
+///
+/// let timeout: Result<(), &str> = Err("deadline exceeded");
+/// if let Err(reason) = assert_sometimes_err!(timeout, "Requests sometimes time out") {
+///     println!("retrying after {}", reason);
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_sometimes_err {
+    (id = $id:literal, $result:expr, $message:tt$(, $details:expr)?) => {
+        $crate::result_helper!(
+            id = $id,
+            expect_ok = false,
+            $result,
+            $message,
+            $(details = $details)?,
+            $crate::assert::AssertType::Sometimes,
+            "Sometimes",
+            must_hit = true
+        )
+    };
+    ($result:expr, $message:tt$(, $details:expr)?) => {
+        $crate::result_helper!(
+            id = $crate::message_template!($message),
+            expect_ok = false,
+            $result,
+            $message,
+            $(details = $details)?,
+            $crate::assert::AssertType::Sometimes,
+            "Sometimes",
+            must_hit = true
+        )
+    };
+    ($($rest:tt)*) => {
+        ::std::compile_error!(
+r#"Invalid syntax when calling macro `assert_sometimes_err`.
+Example usage:
+    `assert_sometimes_err!(result_expr, "assertion message (static literal)", &details_json_value_expr)`
+    `assert_sometimes_err!(id = "stable id (static literal)", result_expr, "assertion message (static literal)", &details_json_value_expr)`
+"#
+        );
+    };
+}
+
+/// Assert that ``option`` is ``Some`` every time this function is called, **and** that it is
+/// called at least once, then evaluate to ``option``.
+/// This test property will be viewable in the ``Antithesis SDK: Always`` group of your triage report.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+/// use antithesis_sdk::assert_always_some_value;
+///
+/// let leaders = HashMap::from([(1, "node-a")]);
+/// let leader = assert_always_some_value!(leaders.get(&1), "Every shard has a leader");
+/// ```
+#[macro_export]
+macro_rules! assert_always_some_value {
+    (id = $id:literal, $option:expr, $message:tt$(, $details:expr)?) => {
+        $crate::option_helper!(
+            id = $id,
+            expect_some = true,
+            $option,
+            $message,
+            $(details = $details)?,
+            $crate::assert::AssertType::Always,
+            "Always",
+            must_hit = true
+        )
+    };
+    ($option:expr, $message:tt$(, $details:expr)?) => {
+        $crate::option_helper!(
+            id = $crate::message_template!($message),
+            expect_some = true,
+            $option,
+            $message,
+            $(details = $details)?,
+            $crate::assert::AssertType::Always,
+            "Always",
+            must_hit = true
+        )
+    };
+    ($($rest:tt)*) => {
+        ::std::compile_error!(
+r#"Invalid syntax when calling macro `assert_always_some_value`.
+Example usage:
+    `assert_always_some_value!(option_expr, "assertion message (static literal)", &details_json_value_expr)`
+    `assert_always_some_value!(id = "stable id (static literal)", option_expr, "assertion message (static literal)", &details_json_value_expr)`
+"#
+        );
+    };
+}
+
+/// Assert that ``option`` is ``Some`` at least one time that this function was called, then evaluate to ``option``.
+/// This test property will be viewable in the ``Antithesis SDK: Sometimes`` group.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+/// use antithesis_sdk::assert_sometimes_some_value;
+///
+/// let cache: HashMap<&str, u32> = HashMap::new();
+/// let cached = assert_sometimes_some_value!(cache.get("user:1"), "Cache lookups sometimes hit");
+/// ```
+#[macro_export]
+macro_rules! assert_sometimes_some_value {
+    (id = $id:literal, $option:expr, $message:tt$(, $details:expr)?) => {
+        $crate::option_helper!(
+            id = $id,
+            expect_some = true,
+            $option,
+            $message,
+            $(details = $details)?,
+            $crate::assert::AssertType::Sometimes,
+            "Sometimes",
+            must_hit = true
+        )
+    };
+    ($option:expr, $message:tt$(, $details:expr)?) => {
+        $crate::option_helper!(
+            id = $crate::message_template!($message),
+            expect_some = true,
+            $option,
+            $message,
+            $(details = $details)?,
+            $crate::assert::AssertType::Sometimes,
+            "Sometimes",
+            must_hit = true
+        )
+    };
+    ($($rest:tt)*) => {
+        ::std::compile_error!(
+r#"Invalid syntax when calling macro `assert_sometimes_some_value`.
+Example usage:
+    `assert_sometimes_some_value!(option_expr, "assertion message (static literal)", &details_json_value_expr)`
+    `assert_sometimes_some_value!(id = "stable id (static literal)", option_expr, "assertion message (static literal)", &details_json_value_expr)`
+"#
+        );
+    };
+}
+
+/// Assert that ``option`` is ``None`` at least one time that this function was called, then evaluate to ``option``.
+/// This test property will be viewable in the ``Antithesis SDK: Sometimes`` group.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+/// use antithesis_sdk::assert_sometimes_none;
+///
+/// let cache: HashMap<&str, u32> = HashMap::new();
+/// let cached = assert_sometimes_none!(cache.get("user:1"), "Cache lookups sometimes miss");
+/// ```
+#[macro_export]
+macro_rules! assert_sometimes_none {
+    (id = $id:literal, $option:expr, $message:tt$(, $details:expr)?) => {
+        $crate::option_helper!(
+            id = $id,
+            expect_some = false,
+            $option,
+            $message,
+            $(details = $details)?,
+            $crate::assert::AssertType::Sometimes,
+            "Sometimes",
+            must_hit = true
+        )
+    };
+    ($option:expr, $message:tt$(, $details:expr)?) => {
+        $crate::option_helper!(
+            id = $crate::message_template!($message),
+            expect_some = false,
+            $option,
+            $message,
+            $(details = $details)?,
+            $crate::assert::AssertType::Sometimes,
+            "Sometimes",
+            must_hit = true
+        )
+    };
+    ($($rest:tt)*) => {
+        ::std::compile_error!(
+r#"Invalid syntax when calling macro `assert_sometimes_none`.
+Example usage:
+    `assert_sometimes_none!(option_expr, "assertion message (static literal)", &details_json_value_expr)`
+    `assert_sometimes_none!(id = "stable id (static literal)", option_expr, "assertion message (static literal)", &details_json_value_expr)`
+"#
+        );
+    };
+}
+
+#[cfg(feature = "full")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! matches_helper {
+    (id = $id:expr, $value:expr, $pattern:pat $(if $guard:expr)?, $message:tt, $(details = $details:expr)?, $assert_type:path, $display_type:literal, must_hit = $must_hit:literal) => {{
+        let details = &();
+        $(let details = $details;)?
+        // As in `assert_matches!`, the fallback arm borrows the value, which is only
+        // rendered if the hit is emitted. Matching in the scrutinee of the outer match
+        // keeps a temporary value alive until then.
+        #[allow(clippy::match_single_binding)]
+        match match $value {
+            $pattern $(if $guard)? => (true, ::std::option::Option::None),
+            ref value => (false, ::std::option::Option::Some(value)),
+        } {
+            (condition, mismatch) => {
+                let details = $crate::assert::outcome::MatchesDetails { details, mismatch };
+                $crate::assert_helper!(
+                    condition = condition,
+                    id = $id,
+                    $message,
+                    details = &details,
+                    $assert_type,
+                    $display_type,
+                    must_hit = $must_hit
+                )
+            }
+        };
+    }};
+}
+
+#[cfg(not(feature = "full"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! matches_helper {
+    (id = $id:expr, $value:expr, $pattern:pat $(if $guard:expr)?, $message:tt, $(details = $details:expr)?, $assert_type:path, $display_type:literal, must_hit = $must_hit:literal) => {{
+        let _condition = ::std::matches!($value, $pattern $(if $guard)?);
+        let _message = || $crate::message_render!($message);
+        $(let details = $details;)?
+    }};
+}
+
+/// Assert that ``value`` matches ``pattern``, as in [`matches!`], every time this function is called, **and** that it is
+/// called at least once. When it does not match, the ``Debug`` rendering of ``value`` is added to the details under ``value``.
+/// The corresponding test property will be viewable in the ``Antithesis SDK: Always`` group of your triage report.
+///
+/// # Example
+///
+/// ```
+/// use serde_json::json;
+/// use antithesis_sdk::assert_always_matches;
+///
+/// #[derive(Debug)]
+/// enum Raft {
+///     Follower,
+///     Candidate { term: u64 },
+///     Leader { term: u64 },
+/// }
+///
+/// let state = Raft::Candidate { term: 3 };
+/// assert_always_matches!(state, Raft::Follower | Raft::Candidate { term: 1.. }, "Node is not leader during election", &json!({"node": 2}));
+/// ```
+#[macro_export]
+macro_rules! assert_always_matches {
+    (id = $id:literal, $value:expr, $pattern:pat $(if $guard:expr)?, $message:tt$(, $details:expr)?) => {
+        $crate::matches_helper!(
+            id = $id,
+            $value,
+            $pattern $(if $guard)?,
+            $message,
+            $(details = $details)?,
+            $crate::assert::AssertType::Always,
+            "Always",
+            must_hit = true
+        )
+    };
+    ($value:expr, $pattern:pat $(if $guard:expr)?, $message:tt$(, $details:expr)?) => {
+        $crate::matches_helper!(
+            id = $crate::message_template!($message),
+            $value,
+            $pattern $(if $guard)?,
+            $message,
+            $(details = $details)?,
+            $crate::assert::AssertType::Always,
+            "Always",
+            must_hit = true
+        )
+    };
+    ($($rest:tt)*) => {
+        ::std::compile_error!(
+r#"Invalid syntax when calling macro `assert_always_matches`.
+Example usage:
+    `assert_always_matches!(value_expr, Pattern if guard_expr, "assertion message (static literal)", &details_json_value_expr)`
+    `assert_always_matches!(id = "stable id (static literal)", value_expr, Pattern, "assertion message (static literal)", &details_json_value_expr)`
+"#
+        );
+    };
+}
+
+/// Assert that ``value`` matches ``pattern``, as in [`matches!`], at least one time that this function was called.
+/// When it does not match, the ``Debug`` rendering of ``value`` is added to the details under ``value``.
+/// This test property will be viewable in the ``Antithesis SDK: Sometimes`` group.
+///
+/// # Example
+///
+/// ```
+/// use antithesis_sdk::assert_sometimes_matches;
+///
+/// let reply: Result<u32, String> = Ok(3);
+/// assert_sometimes_matches!(reply, Ok(n) if n > 2, "Reply sometimes has a quorum");
+/// ```
+#[macro_export]
+macro_rules! assert_sometimes_matches {
+    (id = $id:literal, $value:expr, $pattern:pat $(if $guard:expr)?, $message:tt$(, $details:expr)?) => {
+        $crate::matches_helper!(
+            id = $id,
+            $value,
+            $pattern $(if $guard)?,
             $message,
             $(details = $details)?,
-            $crate::assert::AssertType::Always,
-            "AlwaysOrUnreachable",
-            must_hit = false
+            $crate::assert::AssertType::Sometimes,
+            "Sometimes",
+            must_hit = true
+        )
+    };
+    ($value:expr, $pattern:pat $(if $guard:expr)?, $message:tt$(, $details:expr)?) => {
+        $crate::matches_helper!(
+            id = $crate::message_template!($message),
+            $value,
+            $pattern $(if $guard)?,
+            $message,
+            $(details = $details)?,
+            $crate::assert::AssertType::Sometimes,
+            "Sometimes",
+            must_hit = true
         )
     };
     ($($rest:tt)*) => {
         ::std::compile_error!(
-r#"Invalid syntax when calling macro `assert_always_or_unreachable`.
+r#"Invalid syntax when calling macro `assert_sometimes_matches`.
 Example usage:
-    `assert_always_or_unreachable!(condition_expr, "assertion message (static literal)", &details_json_value_expr)`
+    `assert_sometimes_matches!(value_expr, Pattern if guard_expr, "assertion message (static literal)", &details_json_value_expr)`
+    `assert_sometimes_matches!(id = "stable id (static literal)", value_expr, Pattern, "assertion message (static literal)", &details_json_value_expr)`
 "#
         );
     };
 }
 
-/// Assert that ``condition`` is true at least one time that this function was called.
-/// (If the assertion is never encountered, the test property will therefore fail.)
-/// This test property will be viewable in the ``Antithesis SDK: Sometimes`` group.
+#[cfg(feature = "full")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eventually_helper {
+    ($deadline:expr, id = $id:expr, $message:tt$(, $details:expr)?) => {{
+        let deadline = $deadline;
+        let details = &$crate::serde_json::json!({});
+        $(let details = $details;)?
+        let site = $crate::assertion_site!(id = $id, $message, $crate::assert::AssertType::Always, "Always", must_hit = true);
+        // The message is kept until the obligation is resolved, so it is rendered now,
+        // unless the property has passed and failed already and nothing more can be emitted.
+        let message = if site.tracker.is_settled() {
+            ::std::borrow::Cow::Borrowed("")
+        } else {
+            $crate::message_render!($message)
+        };
+        $crate::assert::eventually::Obligation::new(site, message, details, deadline)
+    }};
+}
+
+#[cfg(not(feature = "full"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! eventually_helper {
+    ($deadline:expr, id = $id:expr, $message:tt$(, $details:expr)?) => {{
+        let deadline = $deadline;
+        let details = &();
+        $(let details = $details;)?
+        let _message = || $crate::message_render!($message);
+        $crate::assert::eventually::Obligation::new(::std::borrow::Cow::Borrowed($crate::message_template!($message)), details, deadline)
+    }};
+}
+
+/// Assert that, from now on, something eventually happens: returns an [`Obligation`](crate::assert::eventually::Obligation)
+/// that must be resolved before ``deadline``. The property passes when an obligation is resolved in time, and fails when
+/// its deadline passes first. It also fails if it is never created, so it behaves like ``assert_always!``.
+/// This test property will be viewable in the ``Antithesis SDK: Always`` group of your triage report.
 ///
 /// # Example
 ///
 /// ```
-/// use serde_json::{json};
-/// use antithesis_sdk::{assert_sometimes, random};
+/// use std::time::Duration;
+/// use serde_json::json;
+/// use antithesis_sdk::assert::eventually::Deadline;
+/// use antithesis_sdk::assert_eventually;
 ///
-/// const MAX_ALLOWED: u64 = 100;
-/// let actual = random::get_random() % 120u64;
-/// let details = json!({"max_allowed": MAX_ALLOWED, "actual": actual});
-/// antithesis_sdk::assert_sometimes!(actual > MAX_ALLOWED, "Value in range", &details);
+/// let mut lagging_replicas = 3;
+/// // The partition has healed: the replicas must catch up within 10 rounds, or 30 seconds.
+/// let deadline = Deadline::steps(10).or_within(Duration::from_secs(30));
+/// let converged = assert_eventually!(deadline, "Replicas converge after a partition heals", &json!({"replicas": 3}));
+/// while converged.is_pending() {
+///     lagging_replicas -= 1;
+///     converged.check(lagging_replicas == 0);
+/// }
 /// ```
 #[macro_export]
-macro_rules! assert_sometimes {
-    ($condition:expr, $message:literal$(, $details:expr)?) => {
-        $crate::assert_helper!(
-            condition = $condition,
-            $message,
-            $(details = $details)?,
-            $crate::assert::AssertType::Sometimes,
-            "Sometimes",
-            must_hit = true
-        )
+macro_rules! assert_eventually {
+    (id = $id:literal, $deadline:expr, $message:tt$(, $details:expr)?) => {
+        $crate::eventually_helper!($deadline, id = $id, $message$(, $details)?)
+    };
+    ($deadline:expr, $message:tt$(, $details:expr)?) => {
+        $crate::eventually_helper!($deadline, id = $crate::message_template!($message), $message$(, $details)?)
     };
     ($($rest:tt)*) => {
         ::std::compile_error!(
-r#"Invalid syntax when calling macro `assert_sometimes`.
+r#"Invalid syntax when calling macro `assert_eventually`.
 Example usage:
-    `assert_sometimes!(condition_expr, "assertion message (static literal)", &details_json_value_expr)`
+    `assert_eventually!(deadline_expr, "assertion message (static literal)", &details_json_value_expr)`
+    `assert_eventually!(id = "stable id (static literal)", deadline_expr, "assertion message (static literal)", &details_json_value_expr)`
 "#
         );
     };
 }
 
-/// Assert that a line of code is reached at least once.
-/// The corresponding test property will pass if this macro is ever called. (If it is never called the test property will therefore fail.)
-/// This test property will be viewable in the ``Antithesis SDK: Reachablity assertions`` group.
+#[cfg(feature = "full")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! scoped_helper {
+    ($condition:expr, id = $id:expr, $message:tt$(, $details:expr)?) => {{
+        let condition = $condition;
+        let details = &();
+        $(let details = $details;)?
+        let site = $crate::assertion_site!(id = $id, $message, $crate::assert::AssertType::Always, "Always", must_hit = true);
+        // As for `assert_eventually!`, the message is rendered unless nothing more can be emitted.
+        let message = if site.tracker.is_settled() {
+            ::std::borrow::Cow::Borrowed("")
+        } else {
+            $crate::message_render!($message)
+        };
+        $crate::assert::ScopedAlways::new(site, message, details, condition)
+    }};
+}
+
+#[cfg(not(feature = "full"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! scoped_helper {
+    ($condition:expr, id = $id:expr, $message:tt$(, $details:expr)?) => {{
+        let condition = $condition;
+        let details = &();
+        $(let details = $details;)?
+        let _message = || $crate::message_render!($message);
+        $crate::assert::ScopedAlways::new(::std::borrow::Cow::Borrowed($crate::message_template!($message)), details, condition)
+    }};
+}
+
+/// Assert that the closure ``condition`` returns true for the whole lifetime of the returned guard, **and** that such a guard
+/// is created at least once. The condition is checked when the guard is created, at each of its
+/// [checkpoints](crate::assert::ScopedAlways::checkpoint), and when it is dropped; a single assertion is emitted on drop,
+/// with the first violating checkpoint in its details.
+/// This test property will be viewable in the ``Antithesis SDK: Always`` group of your triage report.
 ///
 /// # Example
 ///
 /// ```
-/// use serde_json::{json};
-/// use antithesis_sdk::{assert_reachable, random};
+/// use std::cell::Cell;
+/// use antithesis_sdk::assert_always_scoped;
 ///
-/// const MAX_ALLOWED: u64 = 100;
-/// let actual = random::get_random() % 120u64;
-/// let details = json!({"max_allowed": MAX_ALLOWED, "actual": actual});
-/// if (actual > MAX_ALLOWED) {
-///     antithesis_sdk::assert_reachable!("Value in range", &details);
+/// const CAPACITY: usize = 4;
+/// let queued = Cell::new(0);
+/// {
+///     // The queue stays within capacity while the lock is held
+///     let mut scope = assert_always_scoped!(|| queued.get() <= CAPACITY, "Queue within capacity");
+///     queued.set(queued.get() + 1);
+///     scope.checkpoint("after push");
+///     queued.set(queued.get() - 1);
 /// }
 /// ```
 #[macro_export]
-macro_rules! assert_reachable {
-    ($message:literal$(, $details:expr)?) => {
-        $crate::assert_helper!(
-            condition = true,
-            $message,
-            $(details = $details)?,
-            $crate::assert::AssertType::Reachability,
-            "Reachable",
-            must_hit = true
-        )
+macro_rules! assert_always_scoped {
+    (id = $id:literal, $condition:expr, $message:tt$(, $details:expr)?) => {
+        $crate::scoped_helper!($condition, id = $id, $message$(, $details)?)
+    };
+    ($condition:expr, $message:tt$(, $details:expr)?) => {
+        $crate::scoped_helper!($condition, id = $crate::message_template!($message), $message$(, $details)?)
     };
     ($($rest:tt)*) => {
         ::std::compile_error!(
-r#"Invalid syntax when calling macro `assert_reachable`.
+r#"Invalid syntax when calling macro `assert_always_scoped`.
 Example usage:
-    `assert_reachable!("assertion message (static literal)", &details_json_value_expr)`
+    `assert_always_scoped!(|| condition_expr, "assertion message (static literal)", &details_json_value_expr)`
+    `assert_always_scoped!(id = "stable id (static literal)", || condition_expr, "assertion message (static literal)", &details_json_value_expr)`
 "#
         );
     };
 }
 
-/// Assert that a line of code is never reached.
-/// The corresponding test property will fail if this macro is ever called.
-/// (If it is never called the test property will therefore pass.)
-/// This test property will be viewable in the ``Antithesis SDK: Reachablity assertions`` group.
+#[cfg(feature = "full")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! sometimes_each_helper {
+    (id = $id:expr, max_keys = $max_keys:expr, $key:expr, $condition:expr, $message:tt$(, $details:expr)?) => {{
+        let key = &$key;
+        let condition = $condition;
+        let details = &();
+        $(let details = $details;)?
+        let message = $crate::lazy_message!($message);
+        let site = $crate::assertion_site!(id = $id, $message, $crate::assert::AssertType::Sometimes, "Sometimes", must_hit = true);
+        static KEYS: $crate::assert::each::KeyedTrackers = $crate::assert::each::KeyedTrackers::new();
+        $crate::assert::each::sometimes_each(site, &KEYS, $max_keys, key, condition, message, details)
+    }};
+}
+
+#[cfg(not(feature = "full"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! sometimes_each_helper {
+    (id = $id:expr, max_keys = $max_keys:expr, $key:expr, $condition:expr, $message:tt$(, $details:expr)?) => {{
+        let _max_keys: usize = $max_keys;
+        let _key = &$key;
+        let condition = $condition;
+        let _message = || $crate::message_render!($message);
+        $(let details = $details;)?
+    }};
+}
+
+/// Assert that ``condition`` is true at least one time for each distinct ``key`` that this function is called with.
+/// Each key gets its own test property, named after ``message`` and the key, in the ``Antithesis SDK: Sometimes`` group.
+/// The ``message`` alone names a property that passes if ``condition`` is sometimes true for any key.
+///
+/// The ``key`` can be any value implementing ``Serialize``, and is added to the details.
+/// Only keys that are seen at runtime have a property. To keep the report bounded, each call site tracks
+/// at most 64 keys, or ``max_keys`` when given; further keys are only counted in the property named by ``message``.
 ///
 /// # Example
 ///
 /// ```
-/// use serde_json::{json};
-/// use antithesis_sdk::{assert_unreachable, random};
+/// use antithesis_sdk::assert_sometimes_each;
 ///
-/// const MAX_ALLOWED: u64 = 100;
-/// let actual = random::get_random() % 120u64;
-/// let details = json!({"max_allowed": MAX_ALLOWED, "actual": actual});
-/// if (actual > 120u64) {
-///     antithesis_sdk::assert_unreachable!("Value is above range", &details);
+/// for shard in 0..4 {
+///     let degraded = shard % 2 == 0;
+///     assert_sometimes_each!(shard, degraded, "Shard observed degraded");
 /// }
+/// assert_sometimes_each!(max_keys = 16, "us-east", true, "Region served traffic");
 /// ```
 #[macro_export]
-macro_rules! assert_unreachable {
-    ($message:literal$(, $details:expr)?) => {
-        $crate::assert_helper!(
-            condition = false,
-            $message,
-            $(details = $details)?,
-            $crate::assert::AssertType::Reachability,
-            "Unreachable",
-            must_hit = false
-        )
+macro_rules! assert_sometimes_each {
+    (id = $id:literal, max_keys = $max_keys:expr, $key:expr, $condition:expr, $message:tt$(, $details:expr)?) => {
+        $crate::sometimes_each_helper!(id = $id, max_keys = $max_keys, $key, $condition, $message$(, $details)?)
+    };
+    (id = $id:literal, $key:expr, $condition:expr, $message:tt$(, $details:expr)?) => {
+        $crate::sometimes_each_helper!(id = $id, max_keys = $crate::assert::each::DEFAULT_MAX_KEYS, $key, $condition, $message$(, $details)?)
+    };
+    (max_keys = $max_keys:expr, $key:expr, $condition:expr, $message:tt$(, $details:expr)?) => {
+        $crate::sometimes_each_helper!(id = $crate::message_template!($message), max_keys = $max_keys, $key, $condition, $message$(, $details)?)
+    };
+    ($key:expr, $condition:expr, $message:tt$(, $details:expr)?) => {
+        $crate::sometimes_each_helper!(id = $crate::message_template!($message), max_keys = $crate::assert::each::DEFAULT_MAX_KEYS, $key, $condition, $message$(, $details)?)
     };
     ($($rest:tt)*) => {
         ::std::compile_error!(
-r#"Invalid syntax when calling macro `assert_unreachable`.
+r#"Invalid syntax when calling macro `assert_sometimes_each`.
 Example usage:
-    `assert_unreachable!("assertion message (static literal)", &details_json_value_expr)`
+    `assert_sometimes_each!(key_expr, condition_expr, "assertion message (static literal)", &details_json_value_expr)`
+    `assert_sometimes_each!(id = "stable id (static literal)", max_keys = 16, key_expr, condition_expr, "assertion message (static literal)", &details_json_value_expr)`
 "#
         );
     };
@@ -289,7 +1180,7 @@ Example usage:
 #[doc(hidden)]
 #[macro_export]
 macro_rules! guidance_helper {
-    ($guidance_type:expr, $message:literal, $maximize:literal, $guidance_data:expr) => {
+    ($guidance_type:expr, id = $id:expr, $message:tt, $(message = $lazy:expr,)? $maximize:literal, $guidance_data:expr) => {
         $crate::function!(FUN_NAME);
 
         use $crate::assert::guidance::{GuidanceCatalogInfo, GuidanceType};
@@ -297,8 +1188,8 @@ macro_rules! guidance_helper {
         #[linkme(crate = $crate::linkme)] // Refer to our re-exported linkme.
         static GUIDANCE_CATALOG_ITEM: GuidanceCatalogInfo = GuidanceCatalogInfo {
             guidance_type: $guidance_type,
-            message: $message,
-            id: $message,
+            message: $crate::message_template!($message),
+            id: $id,
             class: ::std::module_path!(),
             function: &FUN_NAME,
             file: ::std::file!(),
@@ -309,8 +1200,8 @@ macro_rules! guidance_helper {
 
         $crate::assert::guidance::guidance_impl(
             $guidance_type,
-            $message,
-            $message,
+            $crate::lazy_message!($message $(, $lazy)?).get(),
+            $id,
             ::std::module_path!(),
             *Lazy::force(&FUN_NAME),
             ::std::file!(),
@@ -327,41 +1218,37 @@ macro_rules! guidance_helper {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! numeric_guidance_helper {
-    ($assert:path, $op:tt, $maximize:literal, $left:expr, $right:expr, $message:literal$(, $details:expr)?) => {{
+    ($assert_type:path, $display_type:literal, $op:tt, $maximize:literal, $left:expr, $right:expr, id = $id:expr, $message:tt$(, $details:expr)?) => {{
         let left = $left;
         let right = $right;
         let details = &$crate::serde_json::json!({});
         $(let details = $details;)?
         let mut details = details.clone();
-        details["left"] = left.into();
-        details["right"] = right.into();
-        $assert!(left $op right, $message, &details);
+        details["left"] = $crate::assert::guidance::Diff::to_json(&left);
+        details["right"] = $crate::assert::guidance::Diff::to_json(&right);
+        // Shared by the assertion and the guidance, so that the message is rendered at most once
+        let message = $crate::lazy_message!($message);
+        $crate::assert_helper!(
+            message = message,
+            condition = left $op right,
+            id = $id,
+            $message,
+            details = &details,
+            $assert_type,
+            $display_type,
+            must_hit = true
+        );
 
         let guidance_data = $crate::serde_json::json!({
-            "left": left,
-            "right": right,
+            "left": $crate::assert::guidance::Diff::to_json(&left),
+            "right": $crate::assert::guidance::Diff::to_json(&right),
         });
-        // TODO: Right now it seems to be impossible for this macro to use the returned
-        // type of `diff` to instanciate the `T` in `Guard<T>`, which has to be
-        // explicitly provided for the static variable `GUARD`.
-        // Instead, we currently fix `T` to be `f64`, and ensure all implementations of `Diff` returns `f64`.
-        // Here are some related language limitations:
-        // - Although `typeof` is a reserved keyword in Rust, it is never implemented. See <https://stackoverflow.com/questions/64890774>.
-        // - Rust does not, and explicitly would not (see https://doc.rust-lang.org/reference/items/static-items.html#statics--generics), support generic static variable.
-        // - Type inference is not performed for static variable, i.e. `Guard<_>` is not allowed.
-        // - Some form of existential type can help, but that's only available in nightly Rust under feature `type_alias_impl_trait`.
-        //
-        // Other approaches I can think of either requires dynamic type tagging that has
-        // runtime overhead, or requires the user of the macro to explicitly provide the type,
-        // which is really not ergonomic and deviate from the APIs from other SDKs.
-        let diff = $crate::assert::guidance::Diff::diff(&left, right);
-        type Guard<T> = $crate::assert::guidance::Guard<$maximize, T>;
-        // TODO: Waiting for [type_alias_impl_trait](https://github.com/rust-lang/rust/issues/63063) to stabilize...
-        // type Distance = impl Minimal;
-        type Distance = f64;
-        static GUARD: Guard<Distance> = Guard::init();
+        // The type of the distance is only known to the compiler, so the call site keeps
+        // a watermark for every kind of distance, and the distance picks its own.
+        let diff = $crate::assert::guidance::Diff::diff(&left, &right);
+        static GUARD: $crate::assert::guidance::Guards<$maximize> = $crate::assert::guidance::Guards::init();
         if GUARD.should_emit(diff) {
-            $crate::guidance_helper!($crate::assert::guidance::GuidanceType::Numeric, $message, $maximize, guidance_data);
+            $crate::guidance_helper!($crate::assert::guidance::GuidanceType::Numeric, id = $id, $message, message = message, $maximize, guidance_data);
         }
     }};
 }
@@ -370,8 +1257,16 @@ macro_rules! numeric_guidance_helper {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! numeric_guidance_helper {
-    ($assert:path, $op:tt, $maximize:literal, $left:expr, $right:expr, $message:literal$(, $details:expr)?) => {
-        assert!($left $op $right, $message$(, $details)?);
+    ($assert_type:path, $display_type:literal, $op:tt, $maximize:literal, $left:expr, $right:expr, id = $id:expr, $message:tt$(, $details:expr)?) => {
+        $crate::assert_helper!(
+            condition = $left $op $right,
+            id = $id,
+            $message,
+            $(details = $details)?,
+            $assert_type,
+            $display_type,
+            must_hit = true
+        )
     };
 }
 
@@ -379,7 +1274,7 @@ macro_rules! numeric_guidance_helper {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! boolean_guidance_helper {
-    ($assert:path, $all:literal, {$($name:ident: $cond:expr),*}, $message:literal$(, $details:expr)?) => {{
+    ($assert_type:path, $display_type:literal, $all:literal, {$($name:ident: $cond:expr),*}, id = $id:expr, $message:tt$(, $details:expr)?) => {{
         let details = &$crate::serde_json::json!({});
         $(let details = $details;)?
         let mut details = details.clone();
@@ -391,8 +1286,19 @@ macro_rules! boolean_guidance_helper {
                 $crate::serde_json::json!({$(::std::stringify!($name): $name),*})
             )
         };
-        $assert!(cond, $message, &details);
-        $crate::guidance_helper!($crate::assert::guidance::GuidanceType::Boolean, $message, $all, guidance_data);
+        // Shared by the assertion and the guidance, so that the message is rendered at most once
+        let message = $crate::lazy_message!($message);
+        $crate::assert_helper!(
+            message = message,
+            condition = cond,
+            id = $id,
+            $message,
+            details = &details,
+            $assert_type,
+            $display_type,
+            must_hit = true
+        );
+        $crate::guidance_helper!($crate::assert::guidance::GuidanceType::Boolean, id = $id, $message, message = message, $all, guidance_data);
     }};
 }
 
@@ -400,26 +1306,38 @@ macro_rules! boolean_guidance_helper {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! boolean_guidance_helper {
-    ($assert:path, $all:literal, {$($name:ident: $cond:expr),*}, $message:literal$(, $details:expr)?) => {{
+    ($assert_type:path, $display_type:literal, $all:literal, {$($name:ident: $cond:expr),*}, id = $id:expr, $message:tt$(, $details:expr)?) => {{
         let cond = {
             $(let $name = $cond;)*
             if $all { true $(&& $name)* } else { false $(|| $name)* }
         };
-        $assert!(cond, $message$(, $details)?);
+        $crate::assert_helper!(
+            condition = cond,
+            id = $id,
+            $message,
+            $(details = $details)?,
+            $assert_type,
+            $display_type,
+            must_hit = true
+        );
     }};
 }
 
 /// `assert_always_greater_than(x, y, ...)` is mostly equivalent to `assert_always!(x > y, ...)`, except Antithesis has more visibility to the value of `x` and `y`, and the assertion details would be merged with `{"left": x, "right": y}`.
 #[macro_export]
 macro_rules! assert_always_greater_than {
-    ($left:expr, $right:expr, $message:literal$(, $details:expr)?) => {
-        $crate::numeric_guidance_helper!($crate::assert_always, >, false, $left, $right, $message$(, $details)?)
+    (id = $id:literal, $left:expr, $right:expr, $message:tt$(, $details:expr)?) => {
+        $crate::numeric_guidance_helper!($crate::assert::AssertType::Always, "Always", >, false, $left, $right, id = $id, $message$(, $details)?)
+    };
+    ($left:expr, $right:expr, $message:tt$(, $details:expr)?) => {
+        $crate::numeric_guidance_helper!($crate::assert::AssertType::Always, "Always", >, false, $left, $right, id = $crate::message_template!($message), $message$(, $details)?)
     };
     ($($rest:tt)*) => {
         ::std::compile_error!(
 r#"Invalid syntax when calling macro `assert_always_greater_than`.
 Example usage:
     `assert_always_greater_than!(left_expr, right_expr, "assertion message (static literal)", &details_json_value_expr)`
+    `assert_always_greater_than!(id = "stable id (static literal)", left_expr, right_expr, "assertion message (static literal)", &details_json_value_expr)`
 "#
         );
     };
@@ -428,14 +1346,18 @@ Example usage:
 /// `assert_always_greater_than_or_equal_to(x, y, ...)` is mostly equivalent to `assert_always!(x >= y, ...)`, except Antithesis has more visibility to the value of `x` and `y`, and the assertion details would be merged with `{"left": x, "right": y}`.
 #[macro_export]
 macro_rules! assert_always_greater_than_or_equal_to {
-    ($left:expr, $right:expr, $message:literal$(, $details:expr)?) => {
-        $crate::numeric_guidance_helper!($crate::assert_always, >=, false, $left, $right, $message$(, $details)?)
+    (id = $id:literal, $left:expr, $right:expr, $message:tt$(, $details:expr)?) => {
+        $crate::numeric_guidance_helper!($crate::assert::AssertType::Always, "Always", >=, false, $left, $right, id = $id, $message$(, $details)?)
+    };
+    ($left:expr, $right:expr, $message:tt$(, $details:expr)?) => {
+        $crate::numeric_guidance_helper!($crate::assert::AssertType::Always, "Always", >=, false, $left, $right, id = $crate::message_template!($message), $message$(, $details)?)
     };
     ($($rest:tt)*) => {
         ::std::compile_error!(
 r#"Invalid syntax when calling macro `assert_always_greater_than_or_equal_to`.
 Example usage:
     `assert_always_greater_than_or_equal_to!(left_expr, right_expr, "assertion message (static literal)", &details_json_value_expr)`
+    `assert_always_greater_than_or_equal_to!(id = "stable id (static literal)", left_expr, right_expr, "assertion message (static literal)", &details_json_value_expr)`
 "#
         );
     };
@@ -444,14 +1366,18 @@ Example usage:
 /// `assert_always_less_than(x, y, ...)` is mostly equivalent to `assert_always!(x < y, ...)`, except Antithesis has more visibility to the value of `x` and `y`, and the assertion details would be merged with `{"left": x, "right": y}`.
 #[macro_export]
 macro_rules! assert_always_less_than {
-    ($left:expr, $right:expr, $message:literal$(, $details:expr)?) => {
-        $crate::numeric_guidance_helper!($crate::assert_always, <, true, $left, $right, $message$(, $details)?)
+    (id = $id:literal, $left:expr, $right:expr, $message:tt$(, $details:expr)?) => {
+        $crate::numeric_guidance_helper!($crate::assert::AssertType::Always, "Always", <, true, $left, $right, id = $id, $message$(, $details)?)
+    };
+    ($left:expr, $right:expr, $message:tt$(, $details:expr)?) => {
+        $crate::numeric_guidance_helper!($crate::assert::AssertType::Always, "Always", <, true, $left, $right, id = $crate::message_template!($message), $message$(, $details)?)
     };
     ($($rest:tt)*) => {
         ::std::compile_error!(
 r#"Invalid syntax when calling macro `assert_always_less_than`.
 Example usage:
     `assert_always_less_than!(left_expr, right_expr, "assertion message (static literal)", &details_json_value_expr)`
+    `assert_always_less_than!(id = "stable id (static literal)", left_expr, right_expr, "assertion message (static literal)", &details_json_value_expr)`
 "#
         );
     };
@@ -460,14 +1386,18 @@ Example usage:
 /// `assert_always_less_than_or_equal_to(x, y, ...)` is mostly equivalent to `assert_always!(x <= y, ...)`, except Antithesis has more visibility to the value of `x` and `y`, and the assertion details would be merged with `{"left": x, "right": y}`.
 #[macro_export]
 macro_rules! assert_always_less_than_or_equal_to {
-    ($left:expr, $right:expr, $message:literal$(, $details:expr)?) => {
-        $crate::numeric_guidance_helper!($crate::assert_always, <=, true, $left, $right, $message$(, $details)?)
+    (id = $id:literal, $left:expr, $right:expr, $message:tt$(, $details:expr)?) => {
+        $crate::numeric_guidance_helper!($crate::assert::AssertType::Always, "Always", <=, true, $left, $right, id = $id, $message$(, $details)?)
+    };
+    ($left:expr, $right:expr, $message:tt$(, $details:expr)?) => {
+        $crate::numeric_guidance_helper!($crate::assert::AssertType::Always, "Always", <=, true, $left, $right, id = $crate::message_template!($message), $message$(, $details)?)
     };
     ($($rest:tt)*) => {
         ::std::compile_error!(
 r#"Invalid syntax when calling macro `assert_always_less_than_or_equal_to`.
 Example usage:
     `assert_always_less_than_or_equal_to!(left_expr, right_expr, "assertion message (static literal)", &details_json_value_expr)`
+    `assert_always_less_than_or_equal_to!(id = "stable id (static literal)", left_expr, right_expr, "assertion message (static literal)", &details_json_value_expr)`
 "#
         );
     };
@@ -476,14 +1406,18 @@ Example usage:
 /// `assert_sometimes_greater_than(x, y, ...)` is mostly equivalent to `assert_sometimes!(x > y, ...)`, except Antithesis has more visibility to the value of `x` and `y`, and the assertion details would be merged with `{"left": x, "right": y}`.
 #[macro_export]
 macro_rules! assert_sometimes_greater_than {
-    ($left:expr, $right:expr, $message:literal$(, $details:expr)?) => {
-        $crate::numeric_guidance_helper!($crate::assert_sometimes, >, true, $left, $right, $message$(, $details)?)
+    (id = $id:literal, $left:expr, $right:expr, $message:tt$(, $details:expr)?) => {
+        $crate::numeric_guidance_helper!($crate::assert::AssertType::Sometimes, "Sometimes", >, true, $left, $right, id = $id, $message$(, $details)?)
+    };
+    ($left:expr, $right:expr, $message:tt$(, $details:expr)?) => {
+        $crate::numeric_guidance_helper!($crate::assert::AssertType::Sometimes, "Sometimes", >, true, $left, $right, id = $crate::message_template!($message), $message$(, $details)?)
     };
     ($($rest:tt)*) => {
         ::std::compile_error!(
 r#"Invalid syntax when calling macro `assert_sometimes_greater_than`.
 Example usage:
     `assert_sometimes_greater_than!(left_expr, right_expr, "assertion message (static literal)", &details_json_value_expr)`
+    `assert_sometimes_greater_than!(id = "stable id (static literal)", left_expr, right_expr, "assertion message (static literal)", &details_json_value_expr)`
 "#
         );
     };
@@ -492,14 +1426,18 @@ Example usage:
 /// `assert_sometimes_greater_than_or_equal_to(x, y, ...)` is mostly equivalent to `assert_sometimes!(x >= y, ...)`, except Antithesis has more visibility to the value of `x` and `y`, and the assertion details would be merged with `{"left": x, "right": y}`.
 #[macro_export]
 macro_rules! assert_sometimes_greater_than_or_equal_to {
-    ($left:expr, $right:expr, $message:literal$(, $details:expr)?) => {
-        $crate::numeric_guidance_helper!($crate::assert_sometimes, >=, true, $left, $right, $message$(, $details)?)
+    (id = $id:literal, $left:expr, $right:expr, $message:tt$(, $details:expr)?) => {
+        $crate::numeric_guidance_helper!($crate::assert::AssertType::Sometimes, "Sometimes", >=, true, $left, $right, id = $id, $message$(, $details)?)
+    };
+    ($left:expr, $right:expr, $message:tt$(, $details:expr)?) => {
+        $crate::numeric_guidance_helper!($crate::assert::AssertType::Sometimes, "Sometimes", >=, true, $left, $right, id = $crate::message_template!($message), $message$(, $details)?)
     };
     ($($rest:tt)*) => {
         ::std::compile_error!(
 r#"Invalid syntax when calling macro `assert_sometimes_greater_than_or_equal_to`.
 Example usage:
     `assert_sometimes_greater_than_or_equal_to!(left_expr, right_expr, "assertion message (static literal)", &details_json_value_expr)`
+    `assert_sometimes_greater_than_or_equal_to!(id = "stable id (static literal)", left_expr, right_expr, "assertion message (static literal)", &details_json_value_expr)`
 "#
         );
     };
@@ -508,14 +1446,18 @@ Example usage:
 /// `assert_sometimes_less_than(x, y, ...)` is mostly equivalent to `assert_sometimes!(x < y, ...)`, except Antithesis has more visibility to the value of `x` and `y`, and the assertion details would be merged with `{"left": x, "right": y}`.
 #[macro_export]
 macro_rules! assert_sometimes_less_than {
-    ($left:expr, $right:expr, $message:literal$(, $details:expr)?) => {
-        $crate::numeric_guidance_helper!($crate::assert_sometimes, <, false, $left, $right, $message$(, $details)?)
+    (id = $id:literal, $left:expr, $right:expr, $message:tt$(, $details:expr)?) => {
+        $crate::numeric_guidance_helper!($crate::assert::AssertType::Sometimes, "Sometimes", <, false, $left, $right, id = $id, $message$(, $details)?)
+    };
+    ($left:expr, $right:expr, $message:tt$(, $details:expr)?) => {
+        $crate::numeric_guidance_helper!($crate::assert::AssertType::Sometimes, "Sometimes", <, false, $left, $right, id = $crate::message_template!($message), $message$(, $details)?)
     };
     ($($rest:tt)*) => {
         ::std::compile_error!(
 r#"Invalid syntax when calling macro `assert_sometimes_less_than`.
 Example usage:
     `assert_sometimes_less_than!(left_expr, right_expr, "assertion message (static literal)", &details_json_value_expr)`
+    `assert_sometimes_less_than!(id = "stable id (static literal)", left_expr, right_expr, "assertion message (static literal)", &details_json_value_expr)`
 "#
         );
     };
@@ -524,14 +1466,179 @@ Example usage:
 /// `assert_sometimes_less_than_or_equal_to(x, y, ...)` is mostly equivalent to `assert_sometimes!(x <= y, ...)`, except Antithesis has more visibility to the value of `x` and `y`, and the assertion details would be merged with `{"left": x, "right": y}`.
 #[macro_export]
 macro_rules! assert_sometimes_less_than_or_equal_to {
-    ($left:expr, $right:expr, $message:literal$(, $details:expr)?) => {
-        $crate::numeric_guidance_helper!($crate::assert_sometimes, <=, false, $left, $right, $message$(, $details)?)
+    (id = $id:literal, $left:expr, $right:expr, $message:tt$(, $details:expr)?) => {
+        $crate::numeric_guidance_helper!($crate::assert::AssertType::Sometimes, "Sometimes", <=, false, $left, $right, id = $id, $message$(, $details)?)
+    };
+    ($left:expr, $right:expr, $message:tt$(, $details:expr)?) => {
+        $crate::numeric_guidance_helper!($crate::assert::AssertType::Sometimes, "Sometimes", <=, false, $left, $right, id = $crate::message_template!($message), $message$(, $details)?)
     };
     ($($rest:tt)*) => {
         ::std::compile_error!(
 r#"Invalid syntax when calling macro `assert_sometimes_less_than_or_equal_to`.
 Example usage:
     `assert_sometimes_less_than_or_equal_to!(left_expr, right_expr, "assertion message (static literal)", &details_json_value_expr)`
+    `assert_sometimes_less_than_or_equal_to!(id = "stable id (static literal)", left_expr, right_expr, "assertion message (static literal)", &details_json_value_expr)`
+"#
+        );
+    };
+}
+
+#[cfg(feature = "full")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! equality_helper {
+    ($assert_type:path, $display_type:literal, $op:tt, $left:expr, $right:expr, id = $id:expr, $message:tt$(, $details:expr)?) => {{
+        // Borrow the operands, as `assert_eq!` does, so they can be used afterwards
+        let left = &$left;
+        let right = &$right;
+        let details = &$crate::serde_json::json!({});
+        $(let details = $details;)?
+        let condition = left $op right;
+        let details = $crate::assert::equality::EqualityDetails { left, right, details };
+        $crate::assert_helper!(condition = condition, id = $id, $message, details = &details, $assert_type, $display_type, must_hit = true);
+    }};
+}
+
+#[cfg(not(feature = "full"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! equality_helper {
+    ($assert_type:path, $display_type:literal, $op:tt, $left:expr, $right:expr, id = $id:expr, $message:tt$(, $details:expr)?) => {
+        $crate::assert_helper!(condition = &$left $op &$right, id = $id, $message, $(details = $details)?, $assert_type, $display_type, must_hit = true)
+    };
+}
+
+/// `assert_always_eq(x, y, ...)` is mostly equivalent to `assert_always!(x == y, ...)`, except the assertion details would be merged with `{"left": x, "right": y}`, and when `x != y` with a `"diff"` listing the JSON pointer of every field that differs between the serialized `x` and `y`.
+#[macro_export]
+macro_rules! assert_always_eq {
+    (id = $id:literal, $left:expr, $right:expr, $message:tt$(, $details:expr)?) => {
+        $crate::equality_helper!($crate::assert::AssertType::Always, "Always", ==, $left, $right, id = $id, $message$(, $details)?)
+    };
+    ($left:expr, $right:expr, $message:tt$(, $details:expr)?) => {
+        $crate::equality_helper!($crate::assert::AssertType::Always, "Always", ==, $left, $right, id = $crate::message_template!($message), $message$(, $details)?)
+    };
+    ($($rest:tt)*) => {
+        ::std::compile_error!(
+r#"Invalid syntax when calling macro `assert_always_eq`.
+Example usage:
+    `assert_always_eq!(left_expr, right_expr, "assertion message (static literal)", &details_json_value_expr)`
+    `assert_always_eq!(id = "stable id (static literal)", left_expr, right_expr, "assertion message (static literal)", &details_json_value_expr)`
+"#
+        );
+    };
+}
+
+/// `assert_always_ne(x, y, ...)` is mostly equivalent to `assert_always!(x != y, ...)`, except the assertion details would be merged with `{"left": x, "right": y}`, and when `x != y` with a `"diff"` listing the JSON pointer of every field that differs between the serialized `x` and `y`.
+#[macro_export]
+macro_rules! assert_always_ne {
+    (id = $id:literal, $left:expr, $right:expr, $message:tt$(, $details:expr)?) => {
+        $crate::equality_helper!($crate::assert::AssertType::Always, "Always", !=, $left, $right, id = $id, $message$(, $details)?)
+    };
+    ($left:expr, $right:expr, $message:tt$(, $details:expr)?) => {
+        $crate::equality_helper!($crate::assert::AssertType::Always, "Always", !=, $left, $right, id = $crate::message_template!($message), $message$(, $details)?)
+    };
+    ($($rest:tt)*) => {
+        ::std::compile_error!(
+r#"Invalid syntax when calling macro `assert_always_ne`.
+Example usage:
+    `assert_always_ne!(left_expr, right_expr, "assertion message (static literal)", &details_json_value_expr)`
+    `assert_always_ne!(id = "stable id (static literal)", left_expr, right_expr, "assertion message (static literal)", &details_json_value_expr)`
+"#
+        );
+    };
+}
+
+/// `assert_sometimes_eq(x, y, ...)` is mostly equivalent to `assert_sometimes!(x == y, ...)`, except the assertion details would be merged with `{"left": x, "right": y}`, and when `x != y` with a `"diff"` listing the JSON pointer of every field that differs between the serialized `x` and `y`.
+#[macro_export]
+macro_rules! assert_sometimes_eq {
+    (id = $id:literal, $left:expr, $right:expr, $message:tt$(, $details:expr)?) => {
+        $crate::equality_helper!($crate::assert::AssertType::Sometimes, "Sometimes", ==, $left, $right, id = $id, $message$(, $details)?)
+    };
+    ($left:expr, $right:expr, $message:tt$(, $details:expr)?) => {
+        $crate::equality_helper!($crate::assert::AssertType::Sometimes, "Sometimes", ==, $left, $right, id = $crate::message_template!($message), $message$(, $details)?)
+    };
+    ($($rest:tt)*) => {
+        ::std::compile_error!(
+r#"Invalid syntax when calling macro `assert_sometimes_eq`.
+Example usage:
+    `assert_sometimes_eq!(left_expr, right_expr, "assertion message (static literal)", &details_json_value_expr)`
+    `assert_sometimes_eq!(id = "stable id (static literal)", left_expr, right_expr, "assertion message (static literal)", &details_json_value_expr)`
+"#
+        );
+    };
+}
+
+/// `assert_sometimes_ne(x, y, ...)` is mostly equivalent to `assert_sometimes!(x != y, ...)`, except the assertion details would be merged with `{"left": x, "right": y}`, and when `x != y` with a `"diff"` listing the JSON pointer of every field that differs between the serialized `x` and `y`.
+#[macro_export]
+macro_rules! assert_sometimes_ne {
+    (id = $id:literal, $left:expr, $right:expr, $message:tt$(, $details:expr)?) => {
+        $crate::equality_helper!($crate::assert::AssertType::Sometimes, "Sometimes", !=, $left, $right, id = $id, $message$(, $details)?)
+    };
+    ($left:expr, $right:expr, $message:tt$(, $details:expr)?) => {
+        $crate::equality_helper!($crate::assert::AssertType::Sometimes, "Sometimes", !=, $left, $right, id = $crate::message_template!($message), $message$(, $details)?)
+    };
+    ($($rest:tt)*) => {
+        ::std::compile_error!(
+r#"Invalid syntax when calling macro `assert_sometimes_ne`.
+Example usage:
+    `assert_sometimes_ne!(left_expr, right_expr, "assertion message (static literal)", &details_json_value_expr)`
+    `assert_sometimes_ne!(id = "stable id (static literal)", left_expr, right_expr, "assertion message (static literal)", &details_json_value_expr)`
+"#
+        );
+    };
+}
+
+#[cfg(feature = "full")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! json_guidance_helper {
+    (id = $id:expr, $state:expr, $message:tt) => {{
+        let guidance_data = $crate::serde_json::to_value(&$state).unwrap_or($crate::serde_json::Value::Null);
+        static FILTER: $crate::assert::guidance::StateFilter = $crate::assert::guidance::StateFilter::new();
+        if FILTER.should_emit(&guidance_data) {
+            $crate::guidance_helper!($crate::assert::guidance::GuidanceType::Json, id = $id, $message, false, guidance_data);
+        }
+    }};
+}
+
+#[cfg(not(feature = "full"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! json_guidance_helper {
+    (id = $id:expr, $state:expr, $message:tt) => {{
+        let _state = &$state;
+        let _message = || $crate::message_render!($message);
+    }};
+}
+
+/// `assert_guidance_json!(state, ...)` reports a small serializable ``state`` to Antithesis, which steers exploration
+/// toward runs that reach states not seen before. Unlike the other macros, it does not define a test property.
+///
+/// Each call site only reports a state when it differs from the last state it reported.
+///
+/// # Example
+///
+/// ```
+/// use serde_json::json;
+/// use antithesis_sdk::assert_guidance_json;
+///
+/// let (term, queue_depth) = (3, 12);
+/// assert_guidance_json!(json!({"term": term, "queue_depth": queue_depth}), "Leader state");
+/// ```
+#[macro_export]
+macro_rules! assert_guidance_json {
+    (id = $id:literal, $state:expr, $message:tt) => {
+        $crate::json_guidance_helper!(id = $id, $state, $message)
+    };
+    ($state:expr, $message:tt) => {
+        $crate::json_guidance_helper!(id = $crate::message_template!($message), $state, $message)
+    };
+    ($($rest:tt)*) => {
+        ::std::compile_error!(
+r#"Invalid syntax when calling macro `assert_guidance_json`.
+Example usage:
+    `assert_guidance_json!(state_expr, "guidance message (static literal)")`
+    `assert_guidance_json!(id = "stable id (static literal)", state_expr, "guidance message (static literal)")`
 "#
         );
     };
@@ -543,14 +1650,18 @@ Example usage:
 /// - The assertion details would be merged with `{"a": x, "b": y, ...}`.
 #[macro_export]
 macro_rules! assert_always_some {
-    ({$($($name:ident: $cond:expr),+ $(,)?)?}, $message:literal$(, $details:expr)?) => {
-        $crate::boolean_guidance_helper!($crate::assert_always, false, {$($($name: $cond),+)?}, $message$(, $details)?);
+    (id = $id:literal, {$($($name:ident: $cond:expr),+ $(,)?)?}, $message:tt$(, $details:expr)?) => {
+        $crate::boolean_guidance_helper!($crate::assert::AssertType::Always, "Always", false, {$($($name: $cond),+)?}, id = $id, $message$(, $details)?);
+    };
+    ({$($($name:ident: $cond:expr),+ $(,)?)?}, $message:tt$(, $details:expr)?) => {
+        $crate::boolean_guidance_helper!($crate::assert::AssertType::Always, "Always", false, {$($($name: $cond),+)?}, id = $crate::message_template!($message), $message$(, $details)?);
     };
     ($($rest:tt)*) => {
         ::std::compile_error!(
 r#"Invalid syntax when calling macro `assert_always_some`.
 Example usage:
     `assert_always_some!({field1: cond1, field2: cond2, ...}, "assertion message (static literal)", &details_json_value_expr)`
+    `assert_always_some!(id = "stable id (static literal)", {field1: cond1, field2: cond2, ...}, "assertion message (static literal)", &details_json_value_expr)`
 "#
         );
     };
@@ -562,14 +1673,18 @@ Example usage:
 /// - The assertion details would be merged with `{"a": x, "b": y, ...}`.
 #[macro_export]
 macro_rules! assert_sometimes_all {
-    ({$($($name:ident: $cond:expr),+ $(,)?)?}, $message:literal$(, $details:expr)?) => {
-        $crate::boolean_guidance_helper!($crate::assert_sometimes, true, {$($($name: $cond),+)?}, $message$(, $details)?);
+    (id = $id:literal, {$($($name:ident: $cond:expr),+ $(,)?)?}, $message:tt$(, $details:expr)?) => {
+        $crate::boolean_guidance_helper!($crate::assert::AssertType::Sometimes, "Sometimes", true, {$($($name: $cond),+)?}, id = $id, $message$(, $details)?);
+    };
+    ({$($($name:ident: $cond:expr),+ $(,)?)?}, $message:tt$(, $details:expr)?) => {
+        $crate::boolean_guidance_helper!($crate::assert::AssertType::Sometimes, "Sometimes", true, {$($($name: $cond),+)?}, id = $crate::message_template!($message), $message$(, $details)?);
     };
     ($($rest:tt)*) => {
         ::std::compile_error!(
 r#"Invalid syntax when calling macro `assert_sometimes_all`.
 Example usage:
     `assert_sometimes_all!({field1: cond1, field2: cond2, ...}, "assertion message (static literal)", &details_json_value_expr)`
+    `assert_sometimes_all!(id = "stable id (static literal)", {field1: cond1, field2: cond2, ...}, "assertion message (static literal)", &details_json_value_expr)`
 "#
         );
     };
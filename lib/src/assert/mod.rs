@@ -7,15 +7,41 @@ use crate::internal;
 use linkme::distributed_slice;
 #[cfg(feature = "full")]
 use once_cell::sync::Lazy;
-use serde::Serialize;
+use std::borrow::Cow;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
+
+use crate::protocol::{Assertion, Location};
 #[cfg(feature = "full")]
-use serde_json::json;
+use serde_json::{json, Map};
 
 mod macros;
 #[doc(hidden)]
 #[cfg(feature = "full")]
 pub mod guidance;
+#[doc(hidden)]
+#[cfg(feature = "full")]
+pub mod equality;
+#[doc(hidden)]
+#[cfg(feature = "full")]
+pub mod outcome;
+
+/// Liveness assertions: obligations that must be resolved before a deadline.
+///
+/// An [`Obligation`](crate::assert::eventually::Obligation) is created by [`assert_eventually!`](crate::assert_eventually)
+/// when some precondition becomes true, for example when a network partition heals. It must then be resolved within a
+/// number of logical steps or an amount of wall time, or the property fails with the elapsed steps and time in its details.
+/// Obligations still pending at [`lifecycle::shutdown`](crate::lifecycle::shutdown) fail as well.
+pub mod eventually;
+
+mod scoped;
+pub use scoped::ScopedAlways;
+
+#[doc(hidden)]
+pub mod each;
+
+#[doc(hidden)]
+pub mod invariants;
 
 /// Catalog of all antithesis assertions provided
 #[doc(hidden)]
@@ -66,8 +92,50 @@ pub(crate) static INIT_CATALOG: Lazy<()> = Lazy::new(|| {
             false,
         )
     }
+    crate::catalog::report_conflicts();
 });
 
+/// Makes a type usable as an operand of the numeric assertion macros, such as
+/// [`assert_always_less_than!`](crate::assert_always_less_than), by measuring it as a primitive number.
+///
+/// The ``Measure`` must be one of the primitive integer or float types. Operands are compared with
+/// their own ``PartialOrd`` for the assertion, while their measures are used for guidance and are
+/// reported as ``left`` and ``right`` in the assertion details.
+/// The SDK implements it for [`Duration`](std::time::Duration), measured in nanoseconds.
+///
+/// # Example
+///
+/// ```
+/// use antithesis_sdk::{assert::GuidanceDistance, assert_always_less_than};
+///
+/// #[derive(Copy, Clone, PartialEq, PartialOrd)]
+/// struct LogIndex(u64);
+///
+/// impl GuidanceDistance for LogIndex {
+///     type Measure = u64;
+///
+///     fn measure(&self) -> u64 {
+///         self.0
+///     }
+/// }
+///
+/// let (applied, committed) = (LogIndex(7), LogIndex(9));
+/// assert_always_less_than!(applied, committed, "Applied entries are committed");
+/// ```
+pub trait GuidanceDistance {
+    type Measure;
+
+    fn measure(&self) -> Self::Measure;
+}
+
+impl GuidanceDistance for std::time::Duration {
+    type Measure = u128;
+
+    fn measure(&self) -> u128 {
+        self.as_nanos()
+    }
+}
+
 pub struct TrackingInfo {
     pub pass_count: AtomicU64,
     pub fail_count: AtomicU64,
@@ -88,23 +156,58 @@ impl TrackingInfo {
     }
 }
 
-#[derive(Copy, Clone, PartialEq, Debug, Serialize)]
-#[serde(rename_all(serialize = "lowercase"))]
+#[cfg(feature = "full")]
+impl TrackingInfo {
+    /// Records a hit with ``condition``, and returns whether it should be emitted:
+    /// only the first pass and the first fail are.
+    #[doc(hidden)]
+    pub fn track(&self, condition: bool) -> bool {
+        let count = if condition { &self.pass_count } else { &self.fail_count };
+        let first = count.fetch_add(1, Ordering::SeqCst) == 0;
+        // Captures in unit tests want to observe every hit, not just the first ones
+        first || crate::testing::is_capturing()
+    }
+
+    /// Returns whether no later hit can be emitted, because both a pass and a fail were.
+    #[doc(hidden)]
+    pub fn is_settled(&self) -> bool {
+        self.pass_count.load(Ordering::SeqCst) > 0
+            && self.fail_count.load(Ordering::SeqCst) > 0
+            && !crate::testing::is_capturing()
+    }
+}
+
+/// The message of an assertion, rendered on first use, so that formatting
+/// is skipped for the hits that are not emitted.
+#[doc(hidden)]
+#[cfg(feature = "full")]
+pub struct LazyMessage<F> {
+    render: F,
+    rendered: once_cell::unsync::OnceCell<Cow<'static, str>>,
+}
+
+#[cfg(feature = "full")]
+impl<F: Fn() -> Cow<'static, str>> LazyMessage<F> {
+    pub fn new(render: F) -> Self {
+        LazyMessage {
+            render,
+            rendered: once_cell::unsync::OnceCell::new(),
+        }
+    }
+
+    pub fn get(&self) -> &str {
+        self.rendered.get_or_init(|| (self.render)())
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum AssertType {
     Always,
     Sometimes,
     Reachability,
 }
 
-#[derive(Serialize, Debug)]
-struct AntithesisLocationInfo<'a> {
-    class: &'a str,
-    function: &'a str,
-    file: &'a str,
-    begin_line: u32,
-    begin_column: u32,
-}
-
 /// Internal representation for assertion catalog
 #[doc(hidden)]
 #[derive(Debug)]
@@ -123,22 +226,124 @@ pub struct AssertionCatalogInfo {
     pub id: &'static str,
 }
 
-#[derive(Serialize, Debug)]
-struct AssertionInfo<'a, S: Serialize> {
-    assert_type: AssertType,
-    display_type: &'a str,
-    condition: bool,
-    message: &'a str,
-    location: AntithesisLocationInfo<'a>,
-    hit: bool,
-    must_hit: bool,
-    id: &'a str,
-    details: &'a S,
+#[cfg(feature = "full")]
+impl AssertionCatalogInfo {
+    fn to_record(&self) -> Assertion<'static> {
+        let location = Location {
+            class: Cow::Borrowed(self.class),
+            function: Cow::Borrowed(*Lazy::force(self.function)),
+            file: Cow::Borrowed(self.file),
+            begin_line: self.begin_line,
+            begin_column: self.begin_column,
+        };
+        Assertion {
+            assert_type: self.assert_type,
+            display_type: Cow::Borrowed(self.display_type),
+            condition: self.condition,
+            message: Cow::Borrowed(self.message),
+            location,
+            hit: false,
+            must_hit: self.must_hit,
+            id: Cow::Borrowed(self.id),
+            details: Value::Null,
+        }
+    }
 }
 
+/// Returns the catalog entry of every assertion compiled into the program,
+/// whether or not it has been reached.
+///
+/// These are the same records that are emitted (with ``hit == false``) when the
+/// catalog is registered by [`antithesis_init`](crate::antithesis_init).
+///
+/// # Example
+///
+/// ```
+/// use antithesis_sdk::{assert, assert_unreachable};
+///
+/// fn never_called() {
+///     assert_unreachable!("Catalog example");
+/// }
+///
+/// assert!(assert::catalog().any(|entry| entry.id == "Catalog example"));
+/// ```
+#[cfg(feature = "full")]
+pub fn catalog() -> impl Iterator<Item = Assertion<'static>> {
+    ANTITHESIS_CATALOG.iter().map(AssertionCatalogInfo::to_record)
+}
+
+/// Returns the path of the function whose body declares the function item ``_f``: the
+/// ``type_name`` of ``_f`` without its own name. This includes the type of methods, and
+/// skips the ``{{closure}}`` that holds the body of an async function.
+#[doc(hidden)]
+#[cfg(feature = "full")]
+pub fn enclosing_function<T>(_f: T) -> &'static str {
+    let name = std::any::type_name::<T>();
+    let mut name = &name[..name.rfind("::").unwrap_or(0)];
+    while let Some(outer) = name.strip_suffix("::{{closure}}") {
+        name = outer;
+    }
+    name
+}
+
+/// The catalog entry and tracker of an assertion call site, so that
+/// assertions can be emitted after the call site itself has returned.
+#[doc(hidden)]
+#[derive(Copy, Clone)]
+#[cfg(feature = "full")]
+pub struct AssertionSite {
+    pub info: &'static AssertionCatalogInfo,
+    pub tracker: &'static TrackingInfo,
+}
+
+#[cfg(feature = "full")]
+impl AssertionSite {
+    // The message is only rendered when the hit is emitted.
+    pub(crate) fn emit<S: Serialize, M: AsRef<str>>(&self, condition: bool, message: impl FnOnce() -> M, details: &S) {
+        if !self.tracker.track(condition) {
+            return;
+        }
+        let info = self.info;
+        let f_name: &str = info.function.as_ref();
+        assert_impl(
+            info.assert_type,
+            info.display_type,
+            condition,
+            message().as_ref(),
+            info.class,
+            f_name,
+            info.file,
+            info.begin_line,
+            info.begin_column,
+            true,
+            info.must_hit,
+            info.id,
+            details,
+            None,
+        )
+    }
+}
+
+// Converts details to a map, so that the SDK can merge its own fields into them.
+// Details that are not a JSON object are kept under the ``details`` key.
+#[cfg(feature = "full")]
+pub(crate) fn details_map<S: Serialize + ?Sized>(details: &S) -> Map<String, Value> {
+    match serde_json::to_value(details).unwrap_or(Value::Null) {
+        Value::Object(map) => map,
+        Value::Null => Map::new(),
+        other => {
+            let mut map = Map::new();
+            map.insert("details".to_owned(), other);
+            map
+        }
+    }
+}
+
+type AssertionInfo<'a, S> = Assertion<'a, &'a S>;
+
 impl<'a, S: Serialize> AssertionInfo<'a, S> {
     #[allow(clippy::too_many_arguments)]
-    pub fn new(
+    fn new(
         assert_type: AssertType,
         display_type: &'a str,
         condition: bool,
@@ -153,23 +358,23 @@ impl<'a, S: Serialize> AssertionInfo<'a, S> {
         id: &'a str,
         details: &'a S,
     ) -> Self {
-        let location = AntithesisLocationInfo {
-            class,
-            function,
-            file,
+        let location = Location {
+            class: Cow::Borrowed(class),
+            function: Cow::Borrowed(function),
+            file: Cow::Borrowed(file),
             begin_line,
             begin_column,
         };
 
-        AssertionInfo {
+        Assertion {
             assert_type,
-            display_type,
+            display_type: Cow::Borrowed(display_type),
             condition,
-            message,
+            message: Cow::Borrowed(message),
             location,
             hit,
             must_hit,
-            id,
+            id: Cow::Borrowed(id),
             details
         }
     }
@@ -201,16 +406,9 @@ impl<S: Serialize> AssertionInfo<'_, S> {
 
         // Record the condition in the associated TrackingInfo entry,
         // and emit the assertion when first seeing a condition
-        let emitting = match (info, self.condition) {
-            (None, _) => true,
-            (Some(info), true) => {
-                let prior_value = info.pass_count.fetch_add(1, Ordering::SeqCst);
-                prior_value == 0
-            }
-            (Some(info), false) => {
-                let prior_value = info.fail_count.fetch_add(1, Ordering::SeqCst);
-                prior_value == 0
-            }
+        let emitting = match info {
+            None => true,
+            Some(info) => info.track(self.condition),
         };
         if emitting {
             Lazy::force(&INIT_CATALOG);
@@ -0,0 +1,150 @@
+use std::error::Error;
+use std::fmt::Debug;
+use std::ops::Deref;
+
+use serde::{Serialize, Serializer};
+use serde_json::{json, Value};
+
+use crate::assert::details_map;
+
+/// Wraps the error of a ``Result`` checked by an assertion macro, to describe it
+/// as precisely as its type allows. Calling ``(&&&Captured(error)).capture()``
+/// picks the first of:
+///
+/// * [`ErrorCapture`] for errors implementing [`Error`],
+/// * [`DerefErrorCapture`] for boxed errors, such as `Box<dyn Error>`,
+/// * [`DebugCapture`] for any other error implementing [`Debug`].
+pub struct Captured<'a, E>(pub &'a E);
+
+pub trait ErrorCapture {
+    fn capture(&self) -> Value;
+}
+
+impl<E: Error> ErrorCapture for &&Captured<'_, E> {
+    fn capture(&self) -> Value {
+        describe_error(self.0)
+    }
+}
+
+pub trait DerefErrorCapture {
+    fn capture(&self) -> Value;
+}
+
+impl<E> DerefErrorCapture for &Captured<'_, E>
+where
+    E: Deref + Debug,
+    E::Target: Error,
+{
+    fn capture(&self) -> Value {
+        describe_error(self.0.deref())
+    }
+}
+
+pub trait DebugCapture {
+    fn capture(&self) -> Value;
+}
+
+impl<E: Debug> DebugCapture for Captured<'_, E> {
+    fn capture(&self) -> Value {
+        json!({ "debug": format!("{:?}", self.0) })
+    }
+}
+
+// The display of the error and of each of its sources, outermost first.
+fn describe_error<E: Error + ?Sized>(error: &E) -> Value {
+    let mut sources = Vec::new();
+    let mut source = error.source();
+    while let Some(cause) = source {
+        sources.push(Value::String(cause.to_string()));
+        source = cause.source();
+    }
+    json!({
+        "display": error.to_string(),
+        "debug": format!("{:?}", error),
+        "sources": sources,
+    })
+}
+
+/// The details of a ``Result`` assertion: ``details``, plus the ``error`` captured
+/// by ``capture`` if the result is an ``Err``. They are only merged when serialized,
+/// so that the hits which are not emitted skip describing the error.
+///
+/// The capture is a closure so that the call site picks it with ``Captured``.
+pub struct ResultDetails<'a, S: ?Sized, F> {
+    pub details: &'a S,
+    pub capture: Option<F>,
+}
+
+impl<S: Serialize + ?Sized, F: Fn() -> Value> Serialize for ResultDetails<'_, S, F> {
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        let mut merged = details_map(self.details);
+        if let Some(capture) = &self.capture {
+            merged.insert("error".to_owned(), capture());
+        }
+        merged.serialize(serializer)
+    }
+}
+
+/// The details of a pattern assertion: ``details``, plus the ``Debug`` rendering
+/// of the ``value`` that did not match, if any. As with [`ResultDetails`], they are
+/// only merged when serialized.
+pub struct MatchesDetails<'a, S: ?Sized, V: ?Sized> {
+    pub details: &'a S,
+    pub mismatch: Option<&'a V>,
+}
+
+impl<S: Serialize + ?Sized, V: Debug + ?Sized> Serialize for MatchesDetails<'_, S, V> {
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        let mut merged = details_map(self.details);
+        if let Some(value) = self.mismatch {
+            merged.insert("value".to_owned(), Value::String(format!("{:?}", value)));
+        }
+        merged.serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+// The borrows select the capture, as in the assertion macros
+#[allow(clippy::needless_borrow)]
+mod tests {
+    use std::fmt;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct Outer(std::io::Error);
+
+    impl fmt::Display for Outer {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "request failed")
+        }
+    }
+
+    impl Error for Outer {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    #[test]
+    fn capture_error_chain() {
+        let error = Outer(std::io::Error::new(std::io::ErrorKind::Other, "connection reset"));
+        let captured = (&&&Captured(&error)).capture();
+        assert_eq!(captured["display"], "request failed");
+        assert_eq!(captured["sources"], json!(["connection reset"]));
+    }
+
+    #[test]
+    fn capture_boxed_error() {
+        let error: Box<dyn Error + Send + Sync> = Box::new(Outer(std::io::Error::new(std::io::ErrorKind::Other, "eof")));
+        let captured = (&&&Captured(&error)).capture();
+        assert_eq!(captured["display"], "request failed");
+        assert_eq!(captured["sources"], json!(["eof"]));
+    }
+
+    #[test]
+    fn capture_debug() {
+        let captured = (&&&Captured(&(404, "not found"))).capture();
+        assert_eq!(captured, json!({"debug": "(404, \"not found\")"}));
+    }
+}
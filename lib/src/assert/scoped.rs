@@ -0,0 +1,103 @@
+use std::borrow::Cow;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+#[cfg(feature = "full")]
+use crate::assert::{details_map, AssertionSite};
+
+#[derive(Serialize)]
+struct Violation {
+    checkpoint: &'static str,
+    index: u64,
+}
+
+#[cfg_attr(not(feature = "full"), allow(dead_code))]
+#[derive(Serialize)]
+struct ScopeDetails<'a> {
+    #[serde(flatten)]
+    details: &'a Map<String, Value>,
+    checkpoints: u64,
+    first_violation: &'a Option<Violation>,
+}
+
+/// Checks that a condition holds for as long as it is alive, created by [`assert_always_scoped!`](crate::assert_always_scoped).
+///
+/// The condition is checked when the guard is created (the ``"enter"`` checkpoint), at every call to
+/// [`checkpoint`](ScopedAlways::checkpoint), and when the guard is dropped (the ``"exit"`` checkpoint).
+/// A single assertion is emitted on drop: it passes if the condition held at every checkpoint, and otherwise
+/// fails with the name and index of the first checkpoint at which it did not.
+pub struct ScopedAlways<F: FnMut() -> bool> {
+    #[cfg(feature = "full")]
+    site: AssertionSite,
+    message: Cow<'static, str>,
+    details: Map<String, Value>,
+    condition: F,
+    checkpoints: u64,
+    first_violation: Option<Violation>,
+}
+
+impl<F: FnMut() -> bool> ScopedAlways<F> {
+    #[doc(hidden)]
+    #[cfg(feature = "full")]
+    pub fn new<S: Serialize + ?Sized>(site: AssertionSite, message: Cow<'static, str>, details: &S, condition: F) -> Self {
+        let mut scope = ScopedAlways {
+            site,
+            message,
+            details: details_map(details),
+            condition,
+            checkpoints: 0,
+            first_violation: None,
+        };
+        scope.checkpoint("enter");
+        scope
+    }
+
+    #[doc(hidden)]
+    #[cfg(not(feature = "full"))]
+    pub fn new<S: Serialize + ?Sized>(message: Cow<'static, str>, _details: &S, condition: F) -> Self {
+        let mut scope = ScopedAlways {
+            message,
+            details: Map::new(),
+            condition,
+            checkpoints: 0,
+            first_violation: None,
+        };
+        scope.checkpoint("enter");
+        scope
+    }
+
+    /// Checks the condition, and returns whether it holds.
+    /// If it is the first violation, ``name`` is reported in the details of the assertion.
+    pub fn checkpoint(&mut self, name: &'static str) -> bool {
+        let index = self.checkpoints;
+        self.checkpoints += 1;
+        let holds = (self.condition)();
+        if !holds && self.first_violation.is_none() {
+            self.first_violation = Some(Violation { checkpoint: name, index });
+        }
+        holds
+    }
+
+    /// Returns true if the condition has held at every checkpoint so far.
+    pub fn holds(&self) -> bool {
+        self.first_violation.is_none()
+    }
+}
+
+impl<F: FnMut() -> bool> Drop for ScopedAlways<F> {
+    fn drop(&mut self) {
+        self.checkpoint("exit");
+        #[cfg(feature = "full")]
+        {
+            let details = ScopeDetails {
+                details: &self.details,
+                checkpoints: self.checkpoints,
+                first_violation: &self.first_violation,
+            };
+            self.site.emit(self.holds(), || &self.message, &details);
+        }
+        #[cfg(not(feature = "full"))]
+        let _ = (&self.message, &self.details);
+    }
+}
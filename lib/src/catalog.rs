@@ -0,0 +1,179 @@
+use std::fmt;
+use std::io::Write;
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use serde_json::json;
+
+use crate::assert;
+use crate::internal;
+use crate::protocol::{Assertion, Guidance, Location};
+
+/// The name of an environment variable, ``ANTITHESIS_SDK_DUMP_CATALOG``.
+///
+/// When it is set, [`antithesis_init`](crate::antithesis_init) prints the output of
+/// [`dump_json`] to stdout and exits the process, without running the program and
+/// without producing any other output.
+pub const DUMP_CATALOG: &str = "ANTITHESIS_SDK_DUMP_CATALOG";
+
+/// The name of an environment variable, ``ANTITHESIS_SDK_STRICT_CATALOG``.
+///
+/// When it is set, [`antithesis_init`](crate::antithesis_init) panics if the catalog
+/// has any [conflicts](conflicts).
+pub const STRICT_CATALOG: &str = "ANTITHESIS_SDK_STRICT_CATALOG";
+
+/// The name of the event emitted for each [`Conflict`] when the catalog is registered.
+pub const CONFLICT_EVENT: &str = "antithesis_catalog_conflict";
+
+/// Assertions sharing an ``id`` whose types disagree.
+///
+/// The ``id`` of an assertion is its message, so assertions with the same message
+/// are reported as a single property. That is intended for assertions of the same
+/// kind at several locations, but when the assert type, display type or ``must_hit``
+/// differ it is usually a copy-paste mistake, and the merged property is meaningless.
+#[derive(Serialize, Clone, PartialEq, Debug)]
+pub struct Conflict {
+    pub id: String,
+    /// The catalog entries using ``id``, ordered by location.
+    pub assertions: Vec<Assertion<'static>>,
+}
+
+impl fmt::Display for Conflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "assertions with id {:?} are incompatible:", self.id)?;
+        for assertion in self.assertions.iter() {
+            let location = &assertion.location;
+            write!(
+                f,
+                "\n  {} (must_hit: {}) at {}:{}:{}",
+                assertion.display_type, assertion.must_hit, location.file, location.begin_line, location.begin_column
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns every ``id`` used by assertions of different assert types, display types
+/// or ``must_hit``, ordered by ``id``.
+///
+/// Each conflict is also emitted as a [`CONFLICT_EVENT`] event when the catalog is registered.
+/// The catalog is fixed when the program is linked, so it is only scanned once.
+///
+/// # Example
+///
+/// ```
+/// use antithesis_sdk::{assert_always, assert_sometimes, catalog};
+///
+/// fn check(x: u32) {
+///     assert_always!(x > 0, "Copy-pasted message");
+///     assert_sometimes!(x > 10, "Copy-pasted message");
+/// }
+///
+/// let conflicts = catalog::conflicts();
+/// assert_eq!(conflicts.len(), 1);
+/// assert_eq!(conflicts[0].id, "Copy-pasted message");
+/// ```
+pub fn conflicts() -> &'static [Conflict] {
+    &CONFLICTS
+}
+
+static CONFLICTS: Lazy<Vec<Conflict>> = Lazy::new(|| {
+    let mut conflicts: Vec<Conflict> = Vec::new();
+    let mut entries = assertions().into_iter().peekable();
+    while let Some(first) = entries.next() {
+        let mut group = vec![first];
+        while let Some(next) = entries.next_if(|next| next.id == group[0].id) {
+            group.push(next);
+        }
+        let compatible = group.iter().all(|entry| {
+            entry.assert_type == group[0].assert_type
+                && entry.display_type == group[0].display_type
+                && entry.must_hit == group[0].must_hit
+        });
+        if !compatible {
+            conflicts.push(Conflict {
+                id: group[0].id.to_string(),
+                assertions: group,
+            });
+        }
+    }
+    conflicts
+});
+
+pub(crate) fn report_conflicts() {
+    for conflict in conflicts() {
+        internal::dispatch_output(&json!({ CONFLICT_EVENT: conflict }));
+    }
+}
+
+pub(crate) fn check_strict() {
+    if std::env::var_os(STRICT_CATALOG).is_none() {
+        return;
+    }
+    let conflicts = conflicts();
+    if !conflicts.is_empty() {
+        let descriptions: Vec<String> = conflicts.iter().map(Conflict::to_string).collect();
+        panic!(
+            "{} is set and the assertion catalog has conflicts:\n{}",
+            STRICT_CATALOG,
+            descriptions.join("\n")
+        );
+    }
+}
+
+/// Returns the catalog entry of every assertion in the program, ordered by ``id``
+/// and then by location.
+pub fn assertions() -> Vec<Assertion<'static>> {
+    let mut entries: Vec<_> = assert::catalog().collect();
+    entries.sort_by(|a, b| catalog_order(&a.id, &a.location).cmp(&catalog_order(&b.id, &b.location)));
+    entries
+}
+
+/// Returns the catalog entry of every guidance in the program, ordered by ``id``
+/// and then by location.
+pub fn guidance() -> Vec<Guidance<'static>> {
+    let mut entries: Vec<_> = assert::guidance::catalog().collect();
+    entries.sort_by(|a, b| catalog_order(&a.id, &a.location).cmp(&catalog_order(&b.id, &b.location)));
+    entries
+}
+
+// The order of catalog entries: by ``id``, and then by location.
+fn catalog_order<'a>(id: &'a str, location: &'a Location) -> impl Ord + 'a {
+    (id, &*location.file, location.begin_line, location.begin_column)
+}
+
+/// Renders the whole catalog as pretty-printed JSON, with the shape
+/// ``{"assertions": [...], "guidance": [...]}``.
+///
+/// Entries are sorted, so that the output of two builds can be diffed to
+/// review which properties were added or removed.
+///
+/// # Example
+///
+/// ```
+/// use antithesis_sdk::{assert_sometimes, catalog};
+///
+/// fn maybe() {
+///     assert_sometimes!(true, "Dumped property");
+/// }
+///
+/// let dump: serde_json::Value = serde_json::from_str(&catalog::dump_json()).unwrap();
+/// let ids: Vec<_> = dump["assertions"].as_array().unwrap().iter().map(|a| &a["id"]).collect();
+/// assert!(ids.contains(&&serde_json::json!("Dumped property")));
+/// ```
+pub fn dump_json() -> String {
+    let catalog = json!({
+        "assertions": assertions(),
+        "guidance": guidance(),
+    });
+    format!("{:#}", catalog)
+}
+
+pub(crate) fn dump_if_requested() {
+    if std::env::var_os(DUMP_CATALOG).is_some() {
+        let mut stdout = std::io::stdout();
+        let _ = writeln!(stdout, "{}", dump_json());
+        let _ = stdout.flush();
+        std::process::exit(0);
+    }
+}
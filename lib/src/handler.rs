@@ -0,0 +1,140 @@
+use std::io::Error;
+
+use crate::internal;
+
+/// A destination for the SDK's output and a source of its randomness.
+///
+/// Implementations receive every record emitted by the SDK (assertions,
+/// guidance, lifecycle events and the SDK version header) as a single line of
+/// compact JSON, and provide the values returned by [`crate::random::get_random`].
+///
+/// A handler must be [installed](install_handler) before the SDK emits
+/// anything; once the first record has been dispatched, the active handler
+/// is fixed for the lifetime of the process.
+///
+/// # Example
+///
+/// ```
+/// use std::io::Error;
+/// use antithesis_sdk::handler::Handler;
+///
+/// struct StderrHandler;
+///
+/// impl Handler for StderrHandler {
+///     fn output(&self, value: &str) -> Result<(), Error> {
+///         eprintln!("{value}");
+///         Ok(())
+///     }
+///
+///     fn random(&self) -> u64 {
+///         rand::random::<u64>()
+///     }
+/// }
+/// ```
+pub trait Handler: Send + Sync {
+    /// Consumes one JSON record. The value never contains newlines, so
+    /// it can be written as-is to a JSONL stream.
+    fn output(&self, value: &str) -> Result<(), Error>;
+
+    /// Returns a random u64 value.
+    fn random(&self) -> u64;
+
+    /// Returns a random u64 value, drawn for a structured ``request`` such as
+    /// [`random::range`](crate::random::range).
+    ///
+    /// The SDK maps the value onto the request without bias, and a handler that
+    /// can forward the intent of the request to the platform may return the
+    /// platform's choice directly, encoded as documented on each [`RandomRequest`]
+    /// variant. By default, this calls [`random`](Handler::random).
+    fn structured_random(&self, request: &RandomRequest) -> u64 {
+        let _ = request;
+        self.random()
+    }
+
+    /// Flushes any output that has been buffered by this handler.
+    fn flush(&self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+/// The intent behind a random value requested through the [`random`](crate::random) module.
+///
+/// Each variant documents how the returned u64 is read, so that a handler can encode a
+/// specific choice. Any u64 is accepted, and a uniformly random one gives a uniform result.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum RandomRequest {
+    /// A value below ``bound``, such as an offset in a range.
+    ///
+    /// A value below ``bound`` is used as-is. Larger values are reduced modulo
+    /// ``bound``, except for the few at the top that would bias the result,
+    /// which are requested again.
+    Below { bound: u64 },
+    /// 64 bits of a value between 0 and ``max``, both included, for ranges too wide for
+    /// [`Below`](RandomRequest::Below), whose ``max`` is at least ``u64::MAX``.
+    ///
+    /// When ``max`` is ``u64::MAX``, the value is used as-is. Otherwise it takes two
+    /// requests, the high 64 bits first, and a value up to ``max`` is used as-is,
+    /// while larger values are reduced or requested again as for ``Below``.
+    Wide { max: u128 },
+    /// A boolean that is true with ``probability``.
+    ///
+    /// The result is true if and only if the value is below ``probability * 2^64``,
+    /// so 0 is true unless ``probability`` is 0, and ``u64::MAX`` is false unless it is 1.
+    Bool { probability: f64 },
+    /// A float uniformly distributed between 0 (included) and 1 (excluded).
+    ///
+    /// The result is the top 53 bits of the value, divided by ``2^53``.
+    Unit,
+}
+
+/// Installs ``handler`` as the destination of all SDK output.
+///
+/// This must be called before the first assertion, lifecycle call or
+/// random request, and before [`antithesis_init`](crate::antithesis_init).
+/// If it is never called, the SDK picks a handler the usual way: the
+/// Antithesis platform when it is available, otherwise the file named by
+/// [`LOCAL_OUTPUT`](crate::LOCAL_OUTPUT), otherwise no output at all.
+///
+/// Returns the handler back as an error if a handler is already in use,
+/// either because one was installed earlier or because the SDK has
+/// already produced output.
+///
+/// When the `full` feature is disabled the SDK emits nothing, so the
+/// handler is dropped and `Ok(())` is returned.
+///
+/// # Example
+///
+/// ```
+/// use std::io::Error;
+/// use antithesis_sdk::handler::{self, Handler};
+///
+/// struct StdoutHandler;
+///
+/// impl Handler for StdoutHandler {
+///     fn output(&self, value: &str) -> Result<(), Error> {
+///         println!("{value}");
+///         Ok(())
+///     }
+///
+///     fn random(&self) -> u64 {
+///         rand::random::<u64>()
+///     }
+/// }
+///
+/// fn main() {
+///     if handler::install_handler(Box::new(StdoutHandler)).is_err() {
+///         eprintln!("SDK output was already routed elsewhere");
+///     }
+///     antithesis_sdk::antithesis_init();
+/// }
+/// ```
+#[allow(clippy::needless_doctest_main)]
+pub fn install_handler(handler: Box<dyn Handler>) -> Result<(), Box<dyn Handler>> {
+    internal::install_handler(handler)
+}
+
+/// Asks the active handler to flush any buffered output.
+pub fn flush() {
+    internal::dispatch_flush()
+}
@@ -2,7 +2,8 @@ use std::env;
 use std::fs::File;
 use std::io::{Error, Write};
 
-use crate::internal::{LibHandler, LOCAL_OUTPUT};
+use crate::handler::Handler;
+use crate::internal::LOCAL_OUTPUT;
 
 pub struct LocalHandler {
     writer: File,
@@ -26,7 +27,7 @@ impl LocalHandler {
     }
 }
 
-impl LibHandler for LocalHandler {
+impl Handler for LocalHandler {
     fn output(&self, value: &str) -> Result<(), Error> {
         // The compact Display impl (selected using `{}`) of `serde_json::Value` contains no newlines,
         // hence we are outputing valid JSONL format here.
@@ -41,4 +42,9 @@ impl LibHandler for LocalHandler {
     fn random(&self) -> u64 {
         rand::random::<u64>()
     }
+
+    fn flush(&self) -> Result<(), Error> {
+        let mut writer_mut = &self.writer;
+        writer_mut.flush()
+    }
 }
@@ -1,10 +1,15 @@
 use serde::Serialize;
-use std::io::Error;
 
+use crate::handler::{Handler, RandomRequest};
 use noop_handler::NoOpHandler;
 
 #[cfg(feature = "full")]
 use rustc_version_runtime::version;
+#[cfg(feature = "full")]
+use std::borrow::Cow;
+
+#[cfg(feature = "full")]
+use crate::protocol::{LanguageInfo, SdkInfo, PROTOCOL_VERSION};
 
 #[cfg(feature = "full")]
 use voidstar_handler::VoidstarHandler;
@@ -13,6 +18,8 @@ use local_handler::LocalHandler;
 
 #[cfg(feature = "full")]
 use once_cell::sync::Lazy;
+#[cfg(feature = "full")]
+use std::sync::Mutex;
 
 
 mod noop_handler;
@@ -23,31 +30,12 @@ mod voidstar_handler;
 mod local_handler;
 
 
-#[cfg(feature = "full")]
-#[derive(Serialize, Debug)]
-struct AntithesisLanguageInfo {
-    name: &'static str,
-    version: String,
-}
-
-#[cfg(feature = "full")]
-#[derive(Serialize, Debug)]
-struct AntithesisVersionInfo {
-    language: AntithesisLanguageInfo,
-    sdk_version: &'static str,
-    protocol_version: &'static str,
-}
-
 #[cfg(feature = "full")]
 #[derive(Serialize, Debug)]
 struct AntithesisSDKInfo {
-    antithesis_sdk: AntithesisVersionInfo,
+    antithesis_sdk: SdkInfo<'static>,
 }
 
-// Hardly ever changes, refers to the underlying JSON representation
-#[allow(dead_code)]
-const PROTOCOL_VERSION: &str = "1.1.0";
-
 // Tracks SDK releases
 #[allow(dead_code)]
 const SDK_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -55,7 +43,7 @@ const SDK_VERSION: &str = env!("CARGO_PKG_VERSION");
 pub const LOCAL_OUTPUT: &str = "ANTITHESIS_SDK_LOCAL_OUTPUT";
 
 #[cfg(feature = "full")]
-fn get_handler() -> Box<dyn LibHandler + Sync + Send> {
+fn get_handler() -> Box<dyn Handler> {
     match VoidstarHandler::try_load() {
         Ok(handler) => Box::new(handler),
         Err(_) => match LocalHandler::new() {
@@ -67,13 +55,47 @@ fn get_handler() -> Box<dyn LibHandler + Sync + Send> {
 
 #[cfg(not(feature = "full"))]
 #[allow(dead_code)]
-fn get_handler() -> Box<dyn LibHandler + Sync + Send> {
+fn get_handler() -> Box<dyn Handler> {
     Box::new(NoOpHandler::new())
 }
 
+// A handler installed through `handler::install_handler()` waits here
+// until LIB_HANDLER is initialized. Initialization seals the slot, so
+// that later installations are rejected rather than silently ignored.
+#[cfg(feature = "full")]
+enum HandlerSlot {
+    Empty,
+    Installed(Box<dyn Handler>),
+    Sealed,
+}
+
 #[cfg(feature = "full")]
-pub(crate) static LIB_HANDLER: Lazy<Box<dyn LibHandler + Sync + Send>> = Lazy::new(|| {
-    let handler = get_handler();
+static HANDLER_SLOT: Lazy<Mutex<HandlerSlot>> = Lazy::new(|| Mutex::new(HandlerSlot::Empty));
+
+#[cfg(feature = "full")]
+pub(crate) fn install_handler(handler: Box<dyn Handler>) -> Result<(), Box<dyn Handler>> {
+    let mut slot = HANDLER_SLOT.lock().unwrap();
+    match *slot {
+        HandlerSlot::Empty => {
+            *slot = HandlerSlot::Installed(handler);
+            Ok(())
+        }
+        _ => Err(handler),
+    }
+}
+
+#[cfg(not(feature = "full"))]
+pub(crate) fn install_handler(_handler: Box<dyn Handler>) -> Result<(), Box<dyn Handler>> {
+    Ok(())
+}
+
+#[cfg(feature = "full")]
+pub(crate) static LIB_HANDLER: Lazy<Box<dyn Handler>> = Lazy::new(|| {
+    let installed = std::mem::replace(&mut *HANDLER_SLOT.lock().unwrap(), HandlerSlot::Sealed);
+    let handler = match installed {
+        HandlerSlot::Installed(handler) => handler,
+        _ => get_handler(),
+    };
     let s = serde_json::to_string(&sdk_info()).unwrap_or("{}".to_owned());
     let _ = handler.output(s.as_str());
     handler
@@ -83,16 +105,15 @@ pub(crate) static LIB_HANDLER: Lazy<Box<dyn LibHandler + Sync + Send>> = Lazy::n
 #[cfg(not(feature = "full"))]
 pub(crate) static LIB_HANDLER: NoOpHandler = NoOpHandler{};
 
-pub(crate) trait LibHandler {
-    fn output(&self, value: &str) -> Result<(), Error>;
-    fn random(&self) -> u64;
-}
-
 // Made public so it can be invoked from the antithesis_sdk::random module
 pub(crate) fn dispatch_random() -> u64 {
     LIB_HANDLER.random()
 }
 
+pub(crate) fn dispatch_structured_random(request: &RandomRequest) -> u64 {
+    LIB_HANDLER.structured_random(request)
+}
+
 // Ignore any and all errors - either the output is completed,
 // or it fails silently.
 //
@@ -110,21 +131,30 @@ pub(crate) fn dispatch_random() -> u64 {
 // Made public so it can be invoked from the antithesis_sdk::lifecycle
 // and antithesis_sdk::assert module
 pub fn dispatch_output<T: Serialize + ?Sized>(json_data: &T) {
+    #[cfg(feature = "full")]
+    if crate::testing::try_capture(json_data) {
+        return;
+    }
     let s = serde_json::to_string(json_data).unwrap_or("{}".to_owned());
     let _ = LIB_HANDLER.output(s.as_str());
 }
 
+// Errors are ignored for the same reasons as in dispatch_output()
+pub(crate) fn dispatch_flush() {
+    let _ = LIB_HANDLER.flush();
+}
+
 #[cfg(feature = "full")]
 fn sdk_info() -> AntithesisSDKInfo {
-    let language_data = AntithesisLanguageInfo {
-        name: "Rust",
-        version: version().to_string(),
+    let language_data = LanguageInfo {
+        name: Cow::Borrowed("Rust"),
+        version: Cow::Owned(version().to_string()),
     };
 
-    let version_data = AntithesisVersionInfo {
+    let version_data = SdkInfo {
         language: language_data,
-        sdk_version: SDK_VERSION,
-        protocol_version: PROTOCOL_VERSION,
+        sdk_version: Cow::Borrowed(SDK_VERSION),
+        protocol_version: Cow::Borrowed(PROTOCOL_VERSION),
     };
 
     AntithesisSDKInfo {
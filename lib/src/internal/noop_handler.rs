@@ -1,4 +1,4 @@
-use crate::internal::LibHandler;
+use crate::handler::Handler;
 use std::io::Error;
 
 pub struct NoOpHandler {}
@@ -10,7 +10,7 @@ impl NoOpHandler {
     }
 }
 
-impl LibHandler for NoOpHandler {
+impl Handler for NoOpHandler {
     fn output(&self, _value: &str) -> Result<(), Error> {
         Ok(())
     }
@@ -1,8 +1,9 @@
-use libc::{c_char, size_t};
+use libc::size_t;
 use libloading::Library;
 use std::io::Error;
+use std::os::raw::c_char;
 
-use crate::internal::LibHandler;
+use crate::handler::Handler;
 
 const LIB_NAME: &str = "/usr/lib/libvoidstar.so";
 
@@ -43,7 +44,7 @@ impl VoidstarHandler {
     }
 }
 
-impl LibHandler for VoidstarHandler {
+impl Handler for VoidstarHandler {
     fn output(&self, value: &str) -> Result<(), Error> {
         // SAFETY: The data pointer and length passed into `fuzz_json_data` points to valid memory
         // that we just initialized above.
@@ -57,4 +58,9 @@ impl LibHandler for VoidstarHandler {
     fn random(&self) -> u64 {
         (self.fuzz_get_random)()
     }
+
+    fn flush(&self) -> Result<(), Error> {
+        (self.fuzz_flush)();
+        Ok(())
+    }
 }
@@ -7,6 +7,32 @@
 /// a string literal identifier used to aggregate assertions.
 /// Antithesis generates one test property per unique ``message`` This test property will be named ``message`` in the [triage report](https://antithesis.com/reports/example-triage-report).
 ///
+/// The ``message`` can also be a parenthesized format string and its arguments, as accepted by [`format!`].
+/// The rendered message is emitted with each hit, while the format string itself is used in the
+/// assertion catalog and as the ``id``, so all the messages rendered from it belong to the same test property.
+/// Only the hits that are reported render the message, so its arguments are evaluated at most once per call,
+/// and should not have side effects:
+///
+/// ```
+/// use antithesis_sdk::assert_sometimes;
+///
+/// for replica in 0..3 {
+///     let caught_up = replica < 2;
+///     assert_sometimes!(caught_up, ("Replica {} caught up", replica));
+/// }
+/// ```
+///
+/// Every macro also accepts an optional leading ``id = "..."`` argument. When it is given, the ``id`` identifies
+/// the test property instead of the ``message``, so that the message can be reworded without losing the history
+/// of the property:
+///
+/// ```
+/// use antithesis_sdk::assert_always;
+///
+/// let replicas = 3;
+/// assert_always!(id = "replica-count", replicas > 0, "At least one replica is configured");
+/// ```
+///
 /// Each macro/function also takes a parameter called ``details``, which is a key-value map of optional additional information provided by the user to add context for assertion failures.
 /// The information that is logged will appear in the ``logs`` section of a [triage report](https://antithesis.com/reports/example-triage-report).
 /// Normally the values in ``details`` are evaluated at runtime.
@@ -62,8 +88,77 @@ pub mod lifecycle;
 ///
 /// Multiple flags can coexist if your dependency tree includes more than one
 /// `rand` version.
+///
+/// # `arbitrary` Integration
+///
+/// [`ByteSource`](crate::random::ByteSource) reads bytes chosen by Antithesis,
+/// and makes structured choices such as `int_in_range`, `choose` and `ratio`. With the `arbitrary` feature,
+/// `AntithesisArbitrary` generates values of any type implementing
+/// [`arbitrary::Arbitrary`](https://docs.rs/arbitrary/1/arbitrary/trait.Arbitrary.html) from these bytes,
+/// so that the input types of fuzz targets can be reused as workload operations.
+///
+/// # `proptest` Integration
+///
+/// With the `proptest` feature, `AntithesisRunner` draws values from
+/// [proptest strategies](https://docs.rs/proptest/1/proptest/strategy/trait.Strategy.html) with a generator
+/// seeded by Antithesis for each value, without shrinking, and reports failing cases as assertions.
 pub mod random;
 
+/// The handler module lets you choose where the SDK sends its output.
+///
+/// By default the SDK detects its environment: inside Antithesis, output goes to the platform;
+/// when [const@LOCAL_OUTPUT] is set, output is written to that file as JSONL; otherwise it is discarded.
+/// Installing a custom [`Handler`](crate::handler::Handler) with [`install_handler`](crate::handler::install_handler)
+/// replaces this detection, for example to forward assertions to your own telemetry.
+pub mod handler;
+
+/// The catalog module lists the assertions and guidance compiled into the program, without running them.
+///
+/// Setting the environment variable named by [`DUMP_CATALOG`](crate::catalog::DUMP_CATALOG) makes
+/// [`antithesis_init`](crate::antithesis_init) print the catalog and exit, so that the set of properties
+/// can be compared between releases. Assertions sharing an ``id`` but disagreeing on their type are
+/// reported as [conflicts](crate::catalog::conflicts).
+#[cfg(feature = "full")]
+pub mod catalog;
+
+/// The protocol module defines the JSON records emitted by the SDK.
+///
+/// Every type can be both serialized and deserialized, so that tools consuming the output
+/// written to [const@LOCAL_OUTPUT] can share the schema used by the SDK itself.
+/// See [`parse_jsonl`](crate::protocol::parse_jsonl) to read such a file.
+pub mod protocol;
+
+/// The testing module captures SDK output in memory, so that unit tests can check which assertions were hit.
+///
+/// A [`Capture`](crate::testing::Capture) records the output of the current thread as typed records,
+/// without setting [const@LOCAL_OUTPUT] or parsing a JSONL file, and without interfering with tests running in parallel.
+#[cfg(feature = "full")]
+pub mod testing;
+
+/// Macros placing assertions on whole functions and types, enabled by the `macros` feature.
+///
+/// Each attribute takes the ``message`` of its assertion, and optionally a leading ``id = "..."``,
+/// as the assertion macros do:
+///
+/// * [`reachable`] asserts that the function is called, as [`assert_reachable!`] does,
+/// * [`unreachable`] asserts that the function is never called, as [`assert_unreachable!`] does,
+/// * [`always_returns_ok`] asserts that the function always returns ``Ok``, as [`assert_always_ok!`] does.
+///
+/// They also apply to async functions and to methods, including in trait implementations.
+///
+/// The [`AntithesisInvariants`] derive macro turns ``#[invariant(condition, "message")]`` attributes on a type into
+/// an ``assert_invariants(&self)`` method, which checks each of them with [`assert_always!`].
+/// Since `unreachable` shares its name with the standard macro, refer to them by their path:
+///
+/// ```
+/// #[antithesis_sdk::reachable("Node joins the cluster")]
+/// async fn join(peer: &str) -> usize {
+///     peer.len()
+/// }
+/// ```
+#[cfg(feature = "macros")]
+pub use antithesis_sdk_macros::{always_returns_ok, reachable, unreachable, AntithesisInvariants};
+
 mod internal;
 
 /// Convenience to import all macros and functions
@@ -77,6 +172,10 @@ pub mod prelude;
 /// in the assertion catalog being registered.  If never called,
 /// the assertion catalog will be registered when it encounters the first assertion at runtime.
 ///
+/// If the environment variable named by [`catalog::DUMP_CATALOG`] is set, the catalog is
+/// printed to stdout and the process exits instead. If the environment variable named by
+/// [`catalog::STRICT_CATALOG`] is set, it panics when the catalog has [conflicts](catalog::conflicts).
+///
 /// Example:
 ///
 /// ```
@@ -101,8 +200,10 @@ pub fn antithesis_init() {
 
 #[cfg(feature = "full")]
 fn init() {
+    catalog::dump_if_requested();
     Lazy::force(&internal::LIB_HANDLER);
     Lazy::force(&assert::INIT_CATALOG);
+    catalog::check_strict();
 }
 
 #[cfg(not(feature = "full"))]
@@ -1,16 +1,12 @@
 use crate::internal;
+use crate::protocol::Setup;
 use serde::Serialize;
 use serde_json::{json, Value};
-
-#[derive(Serialize, Debug)]
-struct AntithesisSetupData<'a, 'b> {
-    status: &'a str,
-    details: &'b Value,
-}
+use std::borrow::Cow;
 
 #[derive(Serialize, Debug)]
 struct SetupCompleteData<'a> {
-    antithesis_setup: AntithesisSetupData<'a, 'a>,
+    antithesis_setup: Setup<'a, &'a Value>,
 }
 
 /// Indicates to Antithesis that setup has completed. Call this function when your system and workload are fully initialized.
@@ -35,8 +31,8 @@ struct SetupCompleteData<'a> {
 /// lifecycle::setup_complete(&startup_data);
 /// ```
 pub fn setup_complete(details: &Value) {
-    let status = "complete";
-    let antithesis_setup = AntithesisSetupData::<'_, '_> { status, details };
+    let status = Cow::Borrowed("complete");
+    let antithesis_setup = Setup { status, details };
 
     let setup_complete_data = SetupCompleteData { antithesis_setup };
 
@@ -71,6 +67,25 @@ pub fn send_event(name: &str, details: &Value) {
     internal::dispatch_output(&json_event)
 }
 
+/// Indicates that the program is about to exit. Call this function at the end of your workload.
+///
+/// Every [obligation](crate::assert::eventually::Obligation) that is still pending is reported as failed,
+/// and the output of the SDK is [flushed](crate::handler::flush).
+///
+/// # Example
+///
+/// ```
+/// use antithesis_sdk::lifecycle;
+///
+/// // ... run the workload ...
+/// lifecycle::shutdown();
+/// ```
+pub fn shutdown() {
+    #[cfg(feature = "full")]
+    crate::assert::eventually::settle_pending();
+    crate::handler::flush();
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
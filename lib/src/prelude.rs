@@ -12,6 +12,22 @@ pub use crate::assert_sometimes_greater_than;
 pub use crate::assert_sometimes_greater_than_or_equal_to;
 pub use crate::assert_sometimes_less_than;
 pub use crate::assert_sometimes_less_than_or_equal_to;
+pub use crate::assert_always_eq;
+pub use crate::assert_always_ne;
+pub use crate::assert_sometimes_eq;
+pub use crate::assert_sometimes_ne;
 pub use crate::assert_always_some;
 pub use crate::assert_sometimes_all;
+pub use crate::assert_guidance_json;
+pub use crate::assert_eventually;
+pub use crate::assert_always_scoped;
+pub use crate::assert_sometimes_each;
+pub use crate::assert_always_ok;
+pub use crate::assert_sometimes_ok;
+pub use crate::assert_sometimes_err;
+pub use crate::assert_always_some_value;
+pub use crate::assert_sometimes_some_value;
+pub use crate::assert_sometimes_none;
+pub use crate::assert_always_matches;
+pub use crate::assert_sometimes_matches;
 pub use crate::{antithesis_init, lifecycle, random};
@@ -0,0 +1,235 @@
+use std::borrow::Cow;
+use std::str::FromStr;
+
+use serde::de::{self, Deserializer};
+use serde::ser::{SerializeMap, Serializer};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+pub use crate::assert::AssertType;
+
+/// Version of the JSON representation described by this module.
+///
+/// It is reported in the ``protocol_version`` field of the [`SdkInfo`] header,
+/// which is the first record emitted by the SDK.
+pub const PROTOCOL_VERSION: &str = "1.1.0";
+
+/// The kind of data carried by a [`Guidance`] record.
+#[derive(Copy, Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GuidanceType {
+    Numeric,
+    Boolean,
+    Json,
+}
+
+/// Language the SDK is written in, as reported in the [`SdkInfo`] header.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct LanguageInfo<'a> {
+    pub name: Cow<'a, str>,
+    pub version: Cow<'a, str>,
+}
+
+/// The ``antithesis_sdk`` header, emitted once before any other record.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct SdkInfo<'a> {
+    pub language: LanguageInfo<'a>,
+    pub sdk_version: Cow<'a, str>,
+    pub protocol_version: Cow<'a, str>,
+}
+
+/// Source location of an assertion or guidance.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct Location<'a> {
+    pub class: Cow<'a, str>,
+    pub function: Cow<'a, str>,
+    pub file: Cow<'a, str>,
+    pub begin_line: u32,
+    pub begin_column: u32,
+}
+
+/// An ``antithesis_assert`` record.
+///
+/// Records with ``hit == false`` are assertion catalog entries, emitted once
+/// for every assertion in the program, whether or not it is ever reached.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct Assertion<'a, D = Value> {
+    pub assert_type: AssertType,
+    pub display_type: Cow<'a, str>,
+    pub condition: bool,
+    pub message: Cow<'a, str>,
+    pub location: Location<'a>,
+    pub hit: bool,
+    pub must_hit: bool,
+    pub id: Cow<'a, str>,
+    pub details: D,
+}
+
+/// An ``antithesis_guidance`` record.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct Guidance<'a, D = Value> {
+    pub guidance_type: GuidanceType,
+    pub message: Cow<'a, str>,
+    pub id: Cow<'a, str>,
+    pub location: Location<'a>,
+    pub maximize: bool,
+    pub guidance_data: D,
+    pub hit: bool,
+}
+
+/// An ``antithesis_setup`` record, as sent by [`lifecycle::setup_complete`](crate::lifecycle::setup_complete).
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct Setup<'a, D = Value> {
+    pub status: Cow<'a, str>,
+    pub details: D,
+}
+
+/// A user event, as sent by [`lifecycle::send_event`](crate::lifecycle::send_event).
+///
+/// Events are represented on the wire as ``{name: details}``.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Event<'a, D = Value> {
+    pub name: Cow<'a, str>,
+    pub details: D,
+}
+
+/// Any record emitted by the SDK.
+///
+/// Every record is a JSON object with a single key naming its kind. Objects
+/// whose key is not one of the SDK record kinds are user [events](Event).
+///
+/// # Example
+///
+/// ```
+/// use antithesis_sdk::protocol::Record;
+///
+/// let line = r#"{"antithesis_setup":{"status":"complete","details":{"nodes":3}}}"#;
+/// let record: Record = line.parse().unwrap();
+/// match record {
+///     Record::Setup(setup) => assert_eq!(setup.details["nodes"], 3),
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub enum Record<'a> {
+    Sdk(SdkInfo<'a>),
+    Assertion(Assertion<'a>),
+    Guidance(Guidance<'a>),
+    Setup(Setup<'a>),
+    Event(Event<'a>),
+}
+
+impl Record<'_> {
+    /// The key under which this record is represented on the wire.
+    pub fn kind(&self) -> &str {
+        match self {
+            Record::Sdk(_) => "antithesis_sdk",
+            Record::Assertion(_) => "antithesis_assert",
+            Record::Guidance(_) => "antithesis_guidance",
+            Record::Setup(_) => "antithesis_setup",
+            Record::Event(event) => &event.name,
+        }
+    }
+
+    fn from_entry(name: String, value: Value) -> Result<Record<'static>, serde_json::Error> {
+        let record = match name.as_str() {
+            "antithesis_sdk" => Record::Sdk(serde_json::from_value(value)?),
+            "antithesis_assert" => Record::Assertion(serde_json::from_value(value)?),
+            "antithesis_guidance" => Record::Guidance(serde_json::from_value(value)?),
+            "antithesis_setup" => Record::Setup(serde_json::from_value(value)?),
+            _ => Record::Event(Event {
+                name: Cow::Owned(name),
+                details: value,
+            }),
+        };
+        Ok(record)
+    }
+}
+
+impl Serialize for Record<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(1))?;
+        match self {
+            Record::Sdk(info) => map.serialize_entry(self.kind(), info)?,
+            Record::Assertion(assertion) => map.serialize_entry(self.kind(), assertion)?,
+            Record::Guidance(guidance) => map.serialize_entry(self.kind(), guidance)?,
+            Record::Setup(setup) => map.serialize_entry(self.kind(), setup)?,
+            Record::Event(event) => map.serialize_entry(self.kind(), &event.details)?,
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Record<'static> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let map = Map::<String, Value>::deserialize(deserializer)?;
+        if map.len() != 1 {
+            return Err(de::Error::invalid_length(map.len(), &"an object with a single key"));
+        }
+        let (name, value) = map.into_iter().next().unwrap();
+        Record::from_entry(name, value).map_err(de::Error::custom)
+    }
+}
+
+impl FromStr for Record<'static> {
+    type Err = serde_json::Error;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(line)
+    }
+}
+
+/// Parses the JSONL output written to [`LOCAL_OUTPUT`](crate::LOCAL_OUTPUT),
+/// skipping blank lines.
+///
+/// # Example
+///
+/// ```
+/// use antithesis_sdk::protocol::{self, Record};
+///
+/// let contents = r#"
+/// {"antithesis_sdk":{"language":{"name":"Rust","version":"1.80.0"},"sdk_version":"0.2.8","protocol_version":"1.1.0"}}
+/// {"start_day":{"month":"July"}}
+/// "#;
+/// let records = protocol::parse_jsonl(contents).unwrap();
+/// assert!(matches!(&records[0], Record::Sdk(info) if info.protocol_version == protocol::PROTOCOL_VERSION));
+/// assert_eq!(records[1].kind(), "start_day");
+/// ```
+pub fn parse_jsonl(contents: &str) -> Result<Vec<Record<'static>>, serde_json::Error> {
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(str::parse)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn record_round_trip() {
+        let lines = [
+            json!({"antithesis_sdk": {"language": {"name": "Rust", "version": "1.80.0"}, "sdk_version": "0.2.8", "protocol_version": "1.1.0"}}),
+            json!({"antithesis_assert": {"assert_type": "always", "display_type": "Always", "condition": true, "message": "m", "location": {"class": "c", "function": "f", "file": "src/lib.rs", "begin_line": 1, "begin_column": 5}, "hit": true, "must_hit": true, "id": "m", "details": {"x": 1}}}),
+            json!({"antithesis_guidance": {"guidance_type": "numeric", "message": "g", "id": "g", "location": {"class": "c", "function": "f", "file": "src/lib.rs", "begin_line": 2, "begin_column": 5}, "maximize": false, "guidance_data": {"left": 1, "right": 2}, "hit": true}}),
+            json!({"antithesis_setup": {"status": "complete", "details": {}}}),
+            json!({"my_event": {"y": [1, 2]}}),
+        ];
+        for line in lines.iter() {
+            let record: Record = line.to_string().parse().unwrap();
+            assert_eq!(&serde_json::to_value(&record).unwrap(), line);
+        }
+    }
+
+    #[test]
+    fn record_rejects_multiple_keys() {
+        assert!("{\"a\": 1, \"b\": 2}".parse::<Record>().is_err());
+    }
+
+    #[test]
+    fn record_rejects_malformed_assertion() {
+        assert!("{\"antithesis_assert\": {\"hit\": true}}".parse::<Record>().is_err());
+    }
+}
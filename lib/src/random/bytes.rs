@@ -0,0 +1,109 @@
+use std::io::{self, Read};
+use std::ops::RangeInclusive;
+
+use super::{fill_bytes_impl, random_choice, UniformInt};
+
+/// An endless source of bytes chosen by Antithesis, for code that consumes
+/// randomness as bytes, such as decoders of fuzzing inputs.
+///
+/// Like ``arbitrary::Unstructured``, it can also make structured choices, which
+/// signal to Antithesis how the value is used as the functions of this module do.
+///
+/// Each 8 bytes come from one call to [`get_random`](super::get_random), so the
+/// same advice applies: consume the bytes immediately, rather than storing them
+/// to make decisions later.
+///
+/// # Example
+///
+/// ```
+/// use std::io::Read;
+/// use antithesis_sdk::random::ByteSource;
+///
+/// let mut header = [0u8; 12];
+/// ByteSource.read_exact(&mut header).unwrap();
+/// let payload = ByteSource.next_bytes(64);
+/// assert_eq!(payload.len(), 64);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ByteSource;
+
+impl ByteSource {
+    /// Fills ``dest`` with bytes chosen by Antithesis.
+    pub fn fill(&mut self, dest: &mut [u8]) {
+        fill_bytes_impl(dest)
+    }
+
+    /// Returns ``len`` bytes chosen by Antithesis.
+    pub fn next_bytes(&mut self, len: usize) -> Vec<u8> {
+        let mut bytes = vec![0; len];
+        self.fill(&mut bytes);
+        bytes
+    }
+
+    /// Returns an integer in ``range``, as [`random::range`](super::range) does.
+    ///
+    /// # Panics
+    ///
+    /// Panics if ``range`` is empty.
+    pub fn int_in_range<T: UniformInt>(&mut self, range: RangeInclusive<T>) -> T {
+        super::range(range)
+    }
+
+    /// Returns an item of ``choices``, or ``None`` if it is empty, as [`random_choice`](super::random_choice) does.
+    pub fn choose<'a, T>(&mut self, choices: &'a [T]) -> Option<&'a T> {
+        random_choice(choices)
+    }
+
+    /// Returns true with a probability of ``numerator / denominator``.
+    ///
+    /// # Panics
+    ///
+    /// Panics if ``denominator`` is 0 or less than ``numerator``.
+    pub fn ratio(&mut self, numerator: u64, denominator: u64) -> bool {
+        assert!(
+            0 < denominator && numerator <= denominator,
+            "ByteSource::ratio called with a ratio of {}/{}",
+            numerator,
+            denominator
+        );
+        super::range(0..denominator) < numerator
+    }
+}
+
+/// Reading never fails and never reaches the end of the source.
+impl Read for ByteSource {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.fill(buf);
+        Ok(buf.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_fills_buffer() {
+        let mut buf = [0u8; 37];
+        assert_eq!(ByteSource.read(&mut buf).unwrap(), 37);
+        assert!(buf.iter().any(|b| *b != 0));
+        assert_ne!(ByteSource.next_bytes(16), ByteSource.next_bytes(16));
+        assert!(ByteSource.next_bytes(0).is_empty());
+    }
+
+    #[test]
+    fn read_to_limit() {
+        let mut bytes = Vec::new();
+        ByteSource.take(100).read_to_end(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), 100);
+    }
+
+    #[test]
+    fn structured_choices() {
+        let mut source = ByteSource;
+        assert!((0..50).map(|_| source.int_in_range(-3i8..=3)).all(|v| (-3..=3).contains(&v)));
+        assert_eq!(source.choose(&["only"]), Some(&"only"));
+        assert_eq!(source.choose::<u8>(&[]), None);
+        assert!(source.ratio(5, 5) && !source.ratio(0, 5));
+    }
+}
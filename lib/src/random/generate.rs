@@ -0,0 +1,75 @@
+use arbitrary::{Arbitrary, Unstructured};
+
+use super::ByteSource;
+
+/// Generates values of types implementing [`Arbitrary`](arbitrary::Arbitrary) from bytes chosen by Antithesis,
+/// so that the input types of fuzz targets can be reused as workload operations.
+///
+/// # Example
+///
+/// ```
+/// use antithesis_sdk::random::AntithesisArbitrary;
+///
+/// let (key, value): (u16, Vec<u8>) = AntithesisArbitrary::generate().unwrap();
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AntithesisArbitrary;
+
+impl AntithesisArbitrary {
+    /// The number of bytes first drawn for types whose size has no upper bound.
+    pub const DEFAULT_LEN: usize = 256;
+
+    /// The number of bytes past which [`generate`](Self::generate) stops retrying.
+    pub const MAX_LEN: usize = 4096;
+
+    /// Generates a ``T`` from as many bytes as its [`size_hint`](arbitrary::Arbitrary::size_hint) allows,
+    /// or [`DEFAULT_LEN`](Self::DEFAULT_LEN) bytes when it has no upper bound.
+    ///
+    /// When the bytes run out before the value is complete, it is generated again
+    /// from twice as many new bytes, up to [`MAX_LEN`](Self::MAX_LEN).
+    pub fn generate<T: for<'a> Arbitrary<'a>>() -> arbitrary::Result<T> {
+        let (lower, upper) = T::size_hint(0);
+        let mut len = upper.unwrap_or(Self::DEFAULT_LEN).max(lower);
+        loop {
+            match Self::generate_with_len(len) {
+                Err(arbitrary::Error::NotEnoughData) if len < Self::MAX_LEN => len = (len * 2).min(Self::MAX_LEN),
+                result => return result,
+            }
+        }
+    }
+
+    /// Generates a ``T`` from ``len`` bytes.
+    pub fn generate_with_len<T: for<'a> Arbitrary<'a>>(len: usize) -> arbitrary::Result<T> {
+        let bytes = ByteSource.next_bytes(len);
+        T::arbitrary(&mut Unstructured::new(&bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generate_values() {
+        let values: Vec<(u8, bool)> = (0..100).map(|_| AntithesisArbitrary::generate().unwrap()).collect();
+        assert!(values.iter().any(|(_, b)| *b) && values.iter().any(|(_, b)| !*b));
+        let nested: Vec<Option<u32>> = AntithesisArbitrary::generate_with_len(64).unwrap();
+        assert!(nested.len() <= 64);
+    }
+
+    // Needs more bytes than the default length, without saying so in its size hint.
+    struct Block([u8; 1000]);
+
+    impl<'a> Arbitrary<'a> for Block {
+        fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+            Ok(Block(u.bytes(1000)?.try_into().unwrap()))
+        }
+    }
+
+    #[test]
+    fn generate_grows_on_short_data() {
+        let block: Block = AntithesisArbitrary::generate().unwrap();
+        assert!(block.0.iter().any(|b| *b != 0));
+        assert!(AntithesisArbitrary::generate_with_len::<Block>(999).is_err());
+    }
+}
@@ -0,0 +1,708 @@
+use std::ops::{Bound, RangeBounds};
+use std::time::Duration;
+
+use crate::handler::RandomRequest;
+use crate::internal;
+
+mod bytes;
+pub use bytes::ByteSource;
+
+#[cfg(feature = "arbitrary")]
+mod generate;
+#[cfg(feature = "arbitrary")]
+pub use generate::AntithesisArbitrary;
+
+#[cfg(feature = "proptest")]
+mod strategy;
+#[cfg(feature = "proptest")]
+pub use strategy::AntithesisRunner;
+
+/// Returns a u64 value chosen by Antithesis.
+///
+/// You should use this value immediately rather than using it
+/// later. If you delay, then it is possible for the simulation
+/// to branch in between receiving the random data and using it.
+/// These branches will have the same random value, which
+/// defeats the purpose of branching.
+///
+/// Similarly, do not use the value to seed a pseudo-random
+/// number generator. The PRNG will produce a deterministic
+/// sequence of pseudo-random values based on the seed, so if the
+/// simulation branches, the PRNG will use the same sequence of
+/// values in all branches.
+///
+/// # Example
+///
+/// ```
+/// use antithesis_sdk::random;
+///
+/// let value = random::get_random();
+/// println!("Random value(u64): {value}");
+/// ```
+pub fn get_random() -> u64 {
+    internal::dispatch_random()
+}
+
+/// Returns a randomly chosen item from a list of options.
+///
+/// You should use this value immediately rather than using it
+/// later. If you delay, then it is possible for the simulation
+/// to branch in between receiving the random data and using it.
+/// These branches will have the same random value, which
+/// defeats the purpose of branching.
+///
+/// Similarly, do not use the value to seed a pseudo-random
+/// number generator. The PRNG will produce a deterministic
+/// sequence of pseudo-random values based on the seed, so if the
+/// simulation branches, the PRNG will use the same sequence of
+/// values in all branches.
+///
+/// This function is not purely for convenience. Signaling to
+/// the Antithesis platform that you intend to use a random value
+/// in a structured way enables it to provide more interesting
+/// choices over time.
+///
+/// # Example
+///
+/// ```
+/// use antithesis_sdk::random;
+///
+/// let choices: Vec<&str> = vec!["abc", "def", "xyz", "qrs"];
+/// if let Some(s) = random::random_choice(choices.as_slice()) {
+///     println!("Choice: '{s}'");
+/// };
+/// ```
+pub fn random_choice<T>(slice: &[T]) -> Option<&T> {
+    choose_index(slice.len()).map(|idx| &slice[idx])
+}
+
+/// Returns an index below ``len`` chosen by Antithesis, or ``None`` if ``len`` is 0.
+///
+/// Every index is equally likely. As with [`random_choice`], this signals to the
+/// Antithesis platform that the value is used in a structured way.
+///
+/// # Example
+///
+/// ```
+/// use antithesis_sdk::random;
+///
+/// let mut queue = vec!["a", "b", "c"];
+/// if let Some(idx) = random::choose_index(queue.len()) {
+///     let dropped = queue.remove(idx);
+///     println!("Dropping message {dropped}");
+/// }
+/// ```
+pub fn choose_index(len: usize) -> Option<usize> {
+    match len {
+        0 => None,
+        1 => Some(0),
+        _ => Some(below(len as u64) as usize),
+    }
+}
+
+/// Returns an item chosen by Antithesis from a list of options and their weights.
+///
+/// Each item is chosen with a probability proportional to its weight, so items
+/// with a weight of 0 are never chosen. Returns ``None`` if there are no options
+/// or if all their weights are 0.
+///
+/// # Example
+///
+/// ```
+/// use antithesis_sdk::random;
+///
+/// let operations = [("read", 80), ("write", 19), ("admin", 1)];
+/// if let Some(operation) = random::weighted_choice(&operations) {
+///     println!("Next operation: {operation}");
+/// }
+/// ```
+pub fn weighted_choice<T>(choices: &[(T, u64)]) -> Option<&T> {
+    let total: u128 = choices.iter().map(|(_, weight)| *weight as u128).sum();
+    if total == 0 {
+        return None;
+    }
+    let mut target = below_inclusive(total - 1);
+    choices.iter().find_map(|(item, weight)| {
+        if target < *weight as u128 {
+            Some(item)
+        } else {
+            target -= *weight as u128;
+            None
+        }
+    })
+}
+
+/// Shuffles ``slice`` in place, in an order chosen by Antithesis.
+///
+/// Every order is equally likely. Each position is a separate choice, so that
+/// the Antithesis platform can vary the order one element at a time.
+///
+/// # Example
+///
+/// ```
+/// use antithesis_sdk::random;
+///
+/// let mut operations = vec!["put", "get", "delete"];
+/// random::shuffle(&mut operations);
+/// ```
+pub fn shuffle<T>(slice: &mut [T]) {
+    for i in (1..slice.len()).rev() {
+        let j = below(i as u64 + 1) as usize;
+        slice.swap(i, j);
+    }
+}
+
+/// Returns ``k`` distinct items chosen by Antithesis from ``slice``, in the order they were chosen.
+///
+/// Every selection is equally likely. If ``k`` is greater than the length of ``slice``,
+/// all its items are returned, in a random order.
+///
+/// # Example
+///
+/// ```
+/// use antithesis_sdk::random;
+///
+/// let nodes = ["n1", "n2", "n3", "n4", "n5"];
+/// let partitioned = random::sample(&nodes, 2);
+/// assert_eq!(partitioned.len(), 2);
+/// ```
+pub fn sample<T>(slice: &[T], k: usize) -> Vec<&T> {
+    let mut indices: Vec<usize> = (0..slice.len()).collect();
+    let k = k.min(slice.len());
+    for i in 0..k {
+        let j = i + below((slice.len() - i) as u64) as usize;
+        indices.swap(i, j);
+    }
+    indices[..k].iter().map(|&i| &slice[i]).collect()
+}
+
+/// Returns a subset of ``slice`` chosen by Antithesis, keeping the order of its items.
+///
+/// Each item is included with a probability of one half, as a separate choice,
+/// so the subset may be empty or contain every item.
+///
+/// # Example
+///
+/// ```
+/// use antithesis_sdk::random;
+///
+/// let features = ["compression", "encryption", "checksums"];
+/// for feature in random::subset(&features) {
+///     println!("Enabling {feature}");
+/// }
+/// ```
+pub fn subset<T>(slice: &[T]) -> Vec<&T> {
+    slice.iter().filter(|_| bool_with_probability(0.5)).collect()
+}
+
+/// Returns an integer chosen by Antithesis within ``range``, such as ``0..10`` or ``-5..=5``.
+///
+/// Every value in the range is equally likely: values that would make some
+/// results more likely than others are rejected and drawn again.
+///
+/// As with [`random_choice`], this signals to the Antithesis platform that
+/// the value is used in a structured way.
+///
+/// # Panics
+///
+/// Panics if ``range`` is empty.
+///
+/// # Example
+///
+/// ```
+/// use antithesis_sdk::random;
+///
+/// let replica: usize = random::range(0..3);
+/// let delta: i64 = random::range(-5..=5);
+/// assert!(replica < 3 && (-5..=5).contains(&delta));
+/// ```
+pub fn range<T: UniformInt, R: RangeBounds<T>>(range: R) -> T {
+    let low = match range.start_bound() {
+        Bound::Included(&low) => low.to_ordered(),
+        Bound::Excluded(&low) => low.to_ordered().checked_add(1).expect("random::range called with an empty range"),
+        Bound::Unbounded => T::MIN.to_ordered(),
+    };
+    let high = match range.end_bound() {
+        Bound::Included(&high) => high.to_ordered(),
+        Bound::Excluded(&high) => high.to_ordered().checked_sub(1).expect("random::range called with an empty range"),
+        Bound::Unbounded => T::MAX.to_ordered(),
+    };
+    assert!(low <= high, "random::range called with an empty range");
+    T::from_ordered(low + below_inclusive(high - low))
+}
+
+/// Returns true with ``probability``, as chosen by Antithesis.
+///
+/// # Panics
+///
+/// Panics if ``probability`` is not between 0 and 1.
+///
+/// # Example
+///
+/// ```
+/// use antithesis_sdk::random;
+///
+/// if random::bool_with_probability(0.1) {
+///     println!("Injecting a slow disk");
+/// }
+/// ```
+pub fn bool_with_probability(probability: f64) -> bool {
+    assert!(
+        (0.0..=1.0).contains(&probability),
+        "random::bool_with_probability called with a probability of {}",
+        probability
+    );
+    let value = internal::dispatch_structured_random(&RandomRequest::Bool { probability });
+    bool_from_bits(value, probability)
+}
+
+/// Returns a float chosen by Antithesis, uniformly distributed between 0 (included) and 1 (excluded).
+///
+/// # Example
+///
+/// ```
+/// use antithesis_sdk::random;
+///
+/// let load = random::f64_unit();
+/// assert!((0.0..1.0).contains(&load));
+/// ```
+pub fn f64_unit() -> f64 {
+    unit_from_bits(internal::dispatch_structured_random(&RandomRequest::Unit))
+}
+
+/// Returns a duration chosen by Antithesis between ``low`` and ``high``, both included,
+/// with a nanosecond resolution.
+///
+/// # Panics
+///
+/// Panics if ``low`` is greater than ``high``.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use antithesis_sdk::random;
+///
+/// let timeout = random::duration_between(Duration::from_millis(10), Duration::from_secs(1));
+/// assert!(timeout >= Duration::from_millis(10) && timeout <= Duration::from_secs(1));
+/// ```
+pub fn duration_between(low: Duration, high: Duration) -> Duration {
+    assert!(low <= high, "random::duration_between called with {:?} greater than {:?}", low, high);
+    let nanos = range(low.as_nanos()..=high.as_nanos());
+    Duration::new((nanos / 1_000_000_000) as u64, (nanos % 1_000_000_000) as u32)
+}
+
+/// The integer types that [`range`] can choose from.
+///
+/// This trait is sealed: it is implemented for all the primitive integer types, and cannot be implemented outside the SDK.
+pub trait UniformInt: Copy + private::Sealed {
+    #[doc(hidden)]
+    const MIN: Self;
+    #[doc(hidden)]
+    const MAX: Self;
+
+    // Maps the type onto u128, preserving its order.
+    #[doc(hidden)]
+    fn to_ordered(self) -> u128;
+    #[doc(hidden)]
+    fn from_ordered(value: u128) -> Self;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+macro_rules! uniform_unsigned {
+    ($($t:ty),*) => {$(
+        impl private::Sealed for $t {}
+        impl UniformInt for $t {
+            const MIN: Self = <$t>::MIN;
+            const MAX: Self = <$t>::MAX;
+
+            fn to_ordered(self) -> u128 {
+                self as u128
+            }
+
+            fn from_ordered(value: u128) -> Self {
+                value as $t
+            }
+        }
+    )*};
+}
+
+macro_rules! uniform_signed {
+    ($($t:ty),*) => {$(
+        impl private::Sealed for $t {}
+        impl UniformInt for $t {
+            const MIN: Self = <$t>::MIN;
+            const MAX: Self = <$t>::MAX;
+
+            // Flipping the sign bit orders negative values below positive ones
+            fn to_ordered(self) -> u128 {
+                (self as i128 as u128) ^ (1 << 127)
+            }
+
+            fn from_ordered(value: u128) -> Self {
+                (value ^ (1 << 127)) as i128 as $t
+            }
+        }
+    )*};
+}
+
+uniform_unsigned!(u8, u16, u32, u64, u128, usize);
+uniform_signed!(i8, i16, i32, i64, i128, isize);
+
+// Returns a value between 0 and ``max``, both included.
+fn below_inclusive(max: u128) -> u128 {
+    if max < u64::MAX as u128 {
+        return below(max as u64 + 1) as u128;
+    }
+    let request = RandomRequest::Wide { max };
+    if max == u64::MAX as u128 {
+        return internal::dispatch_structured_random(&request) as u128;
+    }
+    let wide = || {
+        let high = internal::dispatch_structured_random(&request) as u128;
+        high << 64 | internal::dispatch_structured_random(&request) as u128
+    };
+    if max == u128::MAX {
+        return wide();
+    }
+    // As in ``below``, values up to ``max`` are kept
+    let bound = max + 1;
+    let zone = u128::MAX - (0u128.wrapping_sub(bound) % bound);
+    loop {
+        let value = wide();
+        if value <= zone {
+            return value % bound;
+        }
+    }
+}
+
+// Returns a value below ``bound``, which must not be 0.
+fn below(bound: u64) -> u64 {
+    // Values above the zone would make the lower results more likely. Since
+    // the zone is at least ``bound``, values already below ``bound`` are kept.
+    let zone = u64::MAX - (0u64.wrapping_sub(bound) % bound);
+    loop {
+        let value = internal::dispatch_structured_random(&RandomRequest::Below { bound });
+        if value <= zone {
+            return value % bound;
+        }
+    }
+}
+
+// The top 53 bits of ``value``, as a float between 0 and 1.
+fn unit_from_bits(value: u64) -> f64 {
+    (value >> 11) as f64 / (1u64 << 53) as f64
+}
+
+// Whether ``value`` is below ``probability * 2^64``, compared exactly.
+fn bool_from_bits(value: u64, probability: f64) -> bool {
+    // Scaling by a power of two is exact, and so is converting an integral
+    // float below 2^64, so the integer ``value`` is below the threshold
+    // exactly when it is below its ceiling.
+    let threshold = probability * 18_446_744_073_709_551_616.0;
+    threshold >= 18_446_744_073_709_551_616.0 || value < threshold.ceil() as u64
+}
+
+/// A random number generator that uses Antithesis's random number generation.
+///
+/// This implements the `RngCore` trait from the `rand` crate, allowing it to be used
+/// with any code that expects a random number generator from that ecosystem.
+///
+/// # Example
+///
+/// ```
+/// use antithesis_sdk::random::AntithesisRng;
+/// use rand::{Rng, RngCore};
+///
+/// let mut rng = AntithesisRng;
+/// let random_u32: u32 = rng.gen();
+/// let random_u64: u64 = rng.gen();
+/// let random_char: char = rng.gen();
+///
+/// let mut bytes = [0u8; 16];
+/// rng.fill_bytes(&mut bytes);
+/// ```
+pub struct AntithesisRng;
+
+fn fill_bytes_impl(dest: &mut [u8]) {
+    // Split the destination buffer into chunks of 8 bytes each
+    // (since we'll fill each chunk with a u64/8 bytes of random data)
+    let mut chunks = dest.chunks_exact_mut(8);
+
+    // Fill each complete 8-byte chunk with random bytes
+    for chunk in chunks.by_ref() {
+        // Generate 8 random bytes from a u64 in native endian order
+        let random_bytes = get_random().to_ne_bytes();
+        // Copy those random bytes into this chunk
+        chunk.copy_from_slice(&random_bytes);
+    }
+
+    // Get any remaining bytes that didn't fit in a complete 8-byte chunk
+    let remainder = chunks.into_remainder();
+
+    if !remainder.is_empty() {
+        // Generate 8 more random bytes
+        let random_bytes = get_random().to_ne_bytes();
+        // Copy just enough random bytes to fill the remainder
+        remainder.copy_from_slice(&random_bytes[..remainder.len()]);
+    }
+}
+
+// rand-core 0.6 is the underlying core crate for rand 0.8
+#[cfg(feature = "rand_core_v0_6")]
+impl rand_core_v0_6::RngCore for AntithesisRng {
+    fn next_u32(&mut self) -> u32 {
+        get_random() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        get_random()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        fill_bytes_impl(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core_v0_6::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rand_core_v0_9")]
+impl rand_core_v0_9::RngCore for AntithesisRng {
+    fn next_u32(&mut self) -> u32 {
+        get_random() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        get_random()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        fill_bytes_impl(dest)
+    }
+}
+
+#[cfg(feature = "rand_core_v0_10")]
+impl rand_core_v0_10::TryRng for AntithesisRng {
+    type Error = std::convert::Infallible;
+
+    fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
+        Ok(get_random() as u32)
+    }
+
+    fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+        Ok(get_random())
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Self::Error> {
+        Ok(fill_bytes_impl(dest))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::{HashMap, HashSet};
+
+    #[test]
+    fn random_choice_no_choices() {
+        let array = [""; 0];
+        assert_eq!(0, array.len());
+        assert_eq!(None, random_choice(&array))
+    }
+
+    #[test]
+    fn random_choice_one_choice() {
+        let array = ["ABc"; 1];
+        assert_eq!(1, array.len());
+        assert_eq!(Some(&"ABc"), random_choice(&array))
+    }
+
+    #[test]
+    fn random_choice_few_choices() {
+        // For each map key, the value is the count of the number of
+        // random_choice responses received matching that key
+        let mut counted_items: HashMap<&str, i64> = HashMap::new();
+        counted_items.insert("a", 0);
+        counted_items.insert("b", 0);
+        counted_items.insert("c", 0);
+
+        let all_keys: Vec<&str> = counted_items.keys().cloned().collect();
+        assert_eq!(counted_items.len(), all_keys.len());
+        for _i in 0..30 {
+            let rc = random_choice(all_keys.as_slice());
+            if let Some(choice) = rc {
+                if let Some(x) = counted_items.get_mut(choice) {
+                    *x += 1;
+                }
+            }
+        }
+        for (key, val) in counted_items.iter() {
+            assert_ne!(*val, 0, "Did not produce the choice: {}", key);
+        }
+    }
+
+    #[test]
+    fn choose_index_bounds() {
+        assert_eq!(choose_index(0), None);
+        assert_eq!(choose_index(1), Some(0));
+        for _ in 0..1000 {
+            assert!(choose_index(7).unwrap() < 7);
+        }
+    }
+
+    #[test]
+    fn weighted_choice_weights() {
+        assert_eq!(weighted_choice::<&str>(&[]), None);
+        assert_eq!(weighted_choice(&[("a", 0), ("b", 0)]), None);
+        assert_eq!(weighted_choice(&[("a", 0), ("b", 3), ("c", 0)]), Some(&"b"));
+
+        let mut counts: HashMap<&str, u32> = HashMap::new();
+        for _ in 0..10000 {
+            let choice = weighted_choice(&[("read", 90), ("write", 10), ("never", 0)]).unwrap();
+            *counts.entry(choice).or_default() += 1;
+        }
+        assert!(!counts.contains_key("never"));
+        assert!(counts["read"] > counts["write"] * 4);
+        assert!(counts["write"] > 0);
+    }
+
+    #[test]
+    fn weighted_choice_large_weights() {
+        let choices = [(1, u64::MAX), (2, u64::MAX), (3, 0)];
+        for _ in 0..100 {
+            assert_ne!(weighted_choice(&choices), Some(&3));
+        }
+    }
+
+    #[test]
+    fn shuffle_permutes() {
+        let mut seen = HashSet::new();
+        for _ in 0..200 {
+            let mut items = [1, 2, 3];
+            shuffle(&mut items);
+            let mut sorted = items;
+            sorted.sort_unstable();
+            assert_eq!(sorted, [1, 2, 3]);
+            seen.insert(items);
+        }
+        assert_eq!(seen.len(), 6);
+        shuffle::<u8>(&mut []);
+    }
+
+    #[test]
+    fn sample_distinct() {
+        let nodes = ["a", "b", "c", "d", "e"];
+        for _ in 0..200 {
+            let chosen: HashSet<&&str> = sample(&nodes, 3).into_iter().collect();
+            assert_eq!(chosen.len(), 3);
+        }
+        assert_eq!(sample(&nodes, 10).len(), 5);
+        assert!(sample(&nodes, 0).is_empty());
+        assert!(sample::<u8>(&[], 2).is_empty());
+    }
+
+    #[test]
+    fn subset_keeps_order() {
+        let items: Vec<u32> = (0..20).collect();
+        let mut sizes = HashSet::new();
+        for _ in 0..200 {
+            let chosen = subset(&items);
+            assert!(chosen.windows(2).all(|w| w[0] < w[1]));
+            sizes.insert(chosen.len());
+        }
+        assert!(sizes.len() > 1);
+    }
+
+    #[test]
+    fn range_bounds() {
+        for _ in 0..1000 {
+            assert!((3..7).contains(&range(3u8..7)));
+            assert!((-5..=5).contains(&range(-5i64..=5)));
+            assert!(range(..2usize) < 2);
+            assert!(range(i8::MAX - 1..) >= i8::MAX - 1);
+        }
+        assert_eq!(range(4u32..=4), 4);
+        assert_eq!(range(i128::MIN..=i128::MIN), i128::MIN);
+    }
+
+    #[test]
+    fn range_covers_values() {
+        let mut seen = HashSet::new();
+        for _ in 0..1000 {
+            seen.insert(range(-2i16..3));
+        }
+        assert_eq!(seen, (-2..3).collect());
+    }
+
+    #[test]
+    fn range_wide() {
+        let wide = range(u128::MAX - 10..=u128::MAX);
+        assert!(wide >= u128::MAX - 10);
+        let _ = range::<i128, _>(..);
+        let half = range(0..u128::MAX / 2);
+        assert!(half < u128::MAX / 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "empty range")]
+    fn range_empty() {
+        range(5u32..5);
+    }
+
+    #[test]
+    fn ordered_roundtrip() {
+        for v in [i8::MIN, -1, 0, 1, i8::MAX] {
+            assert_eq!(i8::from_ordered(v.to_ordered()), v);
+        }
+        assert!((-1i32).to_ordered() < 0i32.to_ordered());
+        assert!(i128::MIN.to_ordered() < i128::MAX.to_ordered());
+    }
+
+    #[test]
+    fn bool_encoding() {
+        assert!(bool_from_bits(0, 0.25));
+        assert!(bool_from_bits((1 << 62) - 1, 0.25));
+        assert!(!bool_from_bits(1 << 62, 0.25));
+        assert!(bool_from_bits(u64::MAX, 1.0));
+        assert!(!bool_from_bits(u64::MAX, 1.0 - f64::EPSILON));
+        assert!(!bool_from_bits(0, 0.0));
+        assert!(bool_from_bits(0, f64::MIN_POSITIVE));
+        assert_eq!(unit_from_bits(3 << 62), 0.75);
+        assert_eq!(unit_from_bits((1 << 11) - 1), 0.0);
+    }
+
+    #[test]
+    fn bool_probability_extremes() {
+        for _ in 0..100 {
+            assert!(!bool_with_probability(0.0));
+            assert!(bool_with_probability(1.0));
+        }
+    }
+
+    #[test]
+    fn unit_and_duration() {
+        for _ in 0..1000 {
+            assert!((0.0..1.0).contains(&f64_unit()));
+            let d = duration_between(Duration::from_millis(1), Duration::from_millis(2));
+            assert!(d >= Duration::from_millis(1) && d <= Duration::from_millis(2));
+        }
+        assert_eq!(duration_between(Duration::from_secs(1), Duration::from_secs(1)), Duration::from_secs(1));
+        assert!(unit_from_bits(u64::MAX) < 1.0);
+    }
+
+    #[test]
+    fn get_random_100k() {
+        let mut random_numbers: HashSet<u64> = HashSet::new();
+        for _i in 0..100000 {
+            let rn = get_random();
+            assert!(!random_numbers.contains(&rn));
+            random_numbers.insert(rn);
+        }
+    }
+}
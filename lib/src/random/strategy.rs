@@ -0,0 +1,177 @@
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::panic::Location;
+
+use proptest::strategy::{Strategy, ValueTree};
+use proptest::test_runner::{Config, Reason, RngAlgorithm, TestCaseError, TestCaseResult, TestRng, TestRunner};
+use serde_json::json;
+
+use super::ByteSource;
+use crate::assert::{assert_raw, AssertType};
+
+/// Draws values from [proptest](https://docs.rs/proptest) strategies with randomness chosen by Antithesis,
+/// so that existing strategies can generate the operations of a workload.
+///
+/// The generator of each value is seeded from Antithesis right before drawing it. Values are never
+/// shrunk: under Antithesis, the history of a failure is explored by the platform instead.
+///
+/// Antithesis only chooses the 32-byte seed, and proptest derives every choice of the strategy from
+/// it, so the platform cannot steer individual choices as it does for the other functions of this
+/// module. Feeding the choices to proptest as-is, through its pass-through generator, is not an
+/// option: once its bytes run out it returns zeros, on which rejection sampling of ranges never ends.
+///
+/// # Example
+///
+/// ```
+/// use proptest::prelude::*;
+/// use antithesis_sdk::random::AntithesisRunner;
+///
+/// let mut runner = AntithesisRunner::new();
+/// let batch = runner.generate(&prop::collection::vec(0u32..100, 1..10)).unwrap();
+/// assert!(!batch.is_empty());
+///
+/// let _ = runner.check(&(0u32..100, 1u32..10), "Division by a nonzero divisor succeeds", |(n, d)| {
+///     prop_assert!(n.checked_div(d).is_some());
+///     Ok(())
+/// });
+/// ```
+pub struct AntithesisRunner {
+    runner: TestRunner,
+    registered: HashSet<String>,
+}
+
+impl AntithesisRunner {
+    /// Creates a runner with the default proptest configuration.
+    pub fn new() -> Self {
+        Self::with_config(Config::default())
+    }
+
+    /// Creates a runner with ``config``, with shrinking and failure persistence disabled.
+    pub fn with_config(config: Config) -> Self {
+        let config = Config {
+            max_shrink_iters: 0,
+            failure_persistence: None,
+            ..config
+        };
+        // The generator is replaced before each value, so it starts out empty
+        // rather than drawing a seed from Antithesis that would never be used.
+        AntithesisRunner {
+            runner: TestRunner::new_with_rng(config, TestRng::from_seed(RngAlgorithm::PassThrough, &[])),
+            registered: HashSet::new(),
+        }
+    }
+
+    /// Returns a proptest generator seeded from Antithesis.
+    pub fn test_rng() -> TestRng {
+        let mut seed = [0u8; 32];
+        ByteSource.fill(&mut seed);
+        TestRng::from_seed(RngAlgorithm::ChaCha, &seed)
+    }
+
+    /// Draws a value from ``strategy``, or returns why the strategy rejected every value it tried.
+    pub fn generate<S: Strategy>(&mut self, strategy: &S) -> Result<S::Value, Reason> {
+        *self.runner.rng() = Self::test_rng();
+        strategy.new_tree(&mut self.runner).map(|tree| tree.current())
+    }
+
+    /// Draws a value from ``strategy`` and runs ``test`` on it.
+    ///
+    /// When the test fails, an assertion named ``message`` fails as [`assert_unreachable!`](crate::assert_unreachable)
+    /// would, with the ``Debug`` rendering of the value and the reason of the failure in its details.
+    /// The first check of each ``message`` registers the assertion, so that it is reported even if
+    /// no case ever fails. Values rejected by the strategy or by the test are not failures.
+    #[track_caller]
+    pub fn check<S, F>(&mut self, strategy: &S, message: &str, test: F) -> TestCaseResult
+    where
+        S: Strategy,
+        S::Value: Debug,
+        F: FnOnce(S::Value) -> TestCaseResult,
+    {
+        let location = Location::caller();
+        let function = test_function::<F>();
+        let class = function.rfind("::").map_or("", |end| &function[..end]);
+        let report = |details: &serde_json::Value, hit: bool| {
+            assert_raw(
+                false,
+                message.to_owned(),
+                details,
+                class.to_owned(),
+                function.to_owned(),
+                location.file().to_owned(),
+                location.line(),
+                location.column(),
+                hit,
+                false,
+                AssertType::Reachability,
+                "Unreachable".to_owned(),
+                message.to_owned(),
+            )
+        };
+        if self.registered.insert(message.to_owned()) {
+            report(&json!(null), false);
+        }
+        let value = self.generate(strategy).map_err(TestCaseError::Reject)?;
+        let rendered = format!("{:?}", value);
+        let result = test(value);
+        if let Err(TestCaseError::Fail(reason)) = &result {
+            report(&json!({ "value": rendered, "reason": reason.to_string() }), true);
+        }
+        result
+    }
+}
+
+/// Returns the path of the function that defines the test ``F``, skipping the ``{{closure}}`` of
+/// a closure.
+fn test_function<F>() -> &'static str {
+    let mut name = std::any::type_name::<F>();
+    while let Some(outer) = name.strip_suffix("::{{closure}}") {
+        name = outer;
+    }
+    name
+}
+
+impl Default for AntithesisRunner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(all(test, feature = "full"))]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::testing::Capture;
+
+    #[test]
+    fn generate_without_shrinking() {
+        let mut runner = AntithesisRunner::new();
+        let values: Vec<u32> = (0..50).map(|_| runner.generate(&(10u32..20)).unwrap()).collect();
+        assert!(values.iter().all(|v| (10..20).contains(v)));
+        assert!(values.iter().any(|v| *v != values[0]));
+        assert!(runner.generate(&(0u8..10).prop_filter("never", |_| false)).is_err());
+    }
+
+    #[test]
+    fn check_reports_failures() {
+        let capture = Capture::start();
+        let mut runner = AntithesisRunner::new();
+        assert!(runner.check(&Just(4u8), "Even values stay even", |v| {
+            prop_assert_eq!(v % 2, 0);
+            Ok(())
+        }).is_ok());
+        assert!(runner.check(&Just(3u8), "Even values stay even", |v| {
+            prop_assert_eq!(v % 2, 0);
+            Ok(())
+        }).is_err());
+
+        let hits = capture.assertions_with_id("Even values stay even");
+        assert_eq!(hits.iter().map(|a| a.hit).collect::<Vec<_>>(), vec![false, true]);
+        assert!(!hits[1].condition && !hits[1].must_hit);
+        assert_eq!(hits[1].display_type, "Unreachable");
+        assert_eq!(hits[1].details["value"], "3");
+        assert_eq!(hits[1].location.file, file!());
+        assert_eq!(hits[1].location.function, "antithesis_sdk::random::strategy::tests::check_reports_failures");
+        assert_eq!(hits[1].location.class, "antithesis_sdk::random::strategy::tests");
+    }
+}
@@ -0,0 +1,149 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use serde::Serialize;
+
+use crate::protocol::{Assertion, Event, Guidance, Record, Setup};
+
+type Sink = Rc<RefCell<Vec<Record<'static>>>>;
+
+thread_local! {
+    static ACTIVE_SINK: RefCell<Option<Sink>> = const { RefCell::new(None) };
+}
+
+/// Records the SDK output of the current thread in memory, for as long as it is alive.
+///
+/// While a ``Capture`` is active, records emitted on its thread are kept in the
+/// capture as [protocol records](crate::protocol::Record) instead of being sent to the [handler](crate::handler), and every hit
+/// of an assertion is recorded, not only the first passing and failing ones.
+/// Output from other threads is unaffected, so tests using captures can run in
+/// parallel. Captures can be nested; dropping the inner one resumes the outer one.
+///
+/// The assertion catalog is registered before the capture starts, so captured
+/// assertions are all hits.
+///
+/// # Example
+///
+/// ```
+/// use antithesis_sdk::{assert_sometimes, testing::Capture};
+///
+/// let capture = Capture::start();
+/// for i in 0..3 {
+///     assert_sometimes!(i == 2, "Reached the last iteration");
+/// }
+/// let hits = capture.assertions_with_id("Reached the last iteration");
+/// assert_eq!(hits.len(), 3);
+/// assert!(hits.iter().any(|a| a.condition));
+/// ```
+pub struct Capture {
+    sink: Sink,
+    previous: Option<Sink>,
+}
+
+impl Capture {
+    /// Starts capturing the output of the current thread.
+    pub fn start() -> Self {
+        crate::antithesis_init();
+        let sink: Sink = Rc::new(RefCell::new(Vec::new()));
+        let previous = ACTIVE_SINK.with(|active| active.borrow_mut().replace(sink.clone()));
+        Capture { sink, previous }
+    }
+
+    /// Returns every record captured so far, in emission order.
+    pub fn records(&self) -> Vec<Record<'static>> {
+        self.sink.borrow().clone()
+    }
+
+    /// Returns the captured assertions.
+    pub fn assertions(&self) -> Vec<Assertion<'static>> {
+        self.filter(|record| match record {
+            Record::Assertion(assertion) => Some(assertion.clone()),
+            _ => None,
+        })
+    }
+
+    /// Returns the captured assertions whose ``id`` is ``id``.
+    pub fn assertions_with_id(&self, id: &str) -> Vec<Assertion<'static>> {
+        self.filter(|record| match record {
+            Record::Assertion(assertion) if assertion.id == id => Some(assertion.clone()),
+            _ => None,
+        })
+    }
+
+    /// Returns the captured guidance.
+    pub fn guidance(&self) -> Vec<Guidance<'static>> {
+        self.filter(|record| match record {
+            Record::Guidance(guidance) => Some(guidance.clone()),
+            _ => None,
+        })
+    }
+
+    /// Returns the captured setup completions.
+    pub fn setups(&self) -> Vec<Setup<'static>> {
+        self.filter(|record| match record {
+            Record::Setup(setup) => Some(setup.clone()),
+            _ => None,
+        })
+    }
+
+    /// Returns the captured events.
+    pub fn events(&self) -> Vec<Event<'static>> {
+        self.filter(|record| match record {
+            Record::Event(event) => Some(event.clone()),
+            _ => None,
+        })
+    }
+
+    /// Discards the records captured so far.
+    pub fn clear(&self) {
+        self.sink.borrow_mut().clear()
+    }
+
+    fn filter<T>(&self, f: impl FnMut(&Record<'static>) -> Option<T>) -> Vec<T> {
+        self.sink.borrow().iter().filter_map(f).collect()
+    }
+}
+
+impl Drop for Capture {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        ACTIVE_SINK.with(|active| *active.borrow_mut() = previous);
+    }
+}
+
+/// Runs ``f`` while capturing the output of the current thread, and returns
+/// its result along with the captured records.
+///
+/// # Example
+///
+/// ```
+/// use serde_json::json;
+/// use antithesis_sdk::{lifecycle, testing};
+///
+/// let ((), records) = testing::capture(|| {
+///     lifecycle::send_event("checkpoint", &json!({"step": 1}));
+/// });
+/// assert_eq!(records.len(), 1);
+/// ```
+pub fn capture<R>(f: impl FnOnce() -> R) -> (R, Vec<Record<'static>>) {
+    let capture = Capture::start();
+    let result = f();
+    (result, capture.records())
+}
+
+pub(crate) fn is_capturing() -> bool {
+    ACTIVE_SINK.with(|active| active.borrow().is_some())
+}
+
+// Returns true when the record has been captured, in which case it
+// should not be sent to the handler.
+pub(crate) fn try_capture<T: Serialize + ?Sized>(json_data: &T) -> bool {
+    let sink = match ACTIVE_SINK.with(|active| active.borrow().clone()) {
+        Some(sink) => sink,
+        None => return false,
+    };
+    if let Ok(record) = serde_json::to_value(json_data).and_then(serde_json::from_value) {
+        sink.borrow_mut().push(record);
+    }
+    true
+}
@@ -0,0 +1,46 @@
+use std::cell::Cell;
+
+use antithesis_sdk::testing::Capture;
+use antithesis_sdk::{assert_always_scoped, catalog};
+use serde_json::json;
+
+fn hold_lock(queued: &Cell<usize>, pushes: usize) {
+    let mut scope = assert_always_scoped!(|| queued.get() <= 2, "Queue bounded while locked", &json!({"lock": "L"}));
+    for _ in 0..pushes {
+        queued.set(queued.get() + 1);
+        scope.checkpoint("after push");
+    }
+    queued.set(0);
+}
+
+#[test]
+fn scoped_assertions() {
+    let capture = Capture::start();
+    let queued = Cell::new(0);
+    hold_lock(&queued, 2);
+    hold_lock(&queued, 4);
+
+    let hits = capture.assertions_with_id("Queue bounded while locked");
+    assert_eq!(hits.len(), 2);
+    assert!(hits[0].condition);
+    assert_eq!(hits[0].details, json!({"lock": "L", "checkpoints": 4, "first_violation": null}));
+    assert!(!hits[1].condition);
+    assert_eq!(hits[1].details["checkpoints"], 6);
+    assert_eq!(hits[1].details["first_violation"], json!({"checkpoint": "after push", "index": 3}));
+
+    let entry = catalog::assertions().into_iter().find(|a| a.id == "Queue bounded while locked").unwrap();
+    assert!(entry.must_hit);
+}
+
+#[test]
+fn violated_on_entry() {
+    let capture = Capture::start();
+    {
+        let scope = assert_always_scoped!(id = "scoped-entry", || false, ("Scope {}", 1));
+        assert!(!scope.holds());
+    }
+    let hit = &capture.assertions_with_id("scoped-entry")[0];
+    assert!(!hit.condition);
+    assert_eq!(hit.message, "Scope 1");
+    assert_eq!(hit.details["first_violation"]["checkpoint"], "enter");
+}
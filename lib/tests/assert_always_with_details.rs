@@ -2,7 +2,7 @@ use antithesis_sdk::{antithesis_init, assert_always, LOCAL_OUTPUT};
 use serde_json::{json, Value};
 
 mod common;
-use common::{AntithesisAssert, AssertType, SDKInput};
+use antithesis_sdk::protocol::{Assertion, AssertType, Record};
 
 // Expected Output in /tmp/antithesis-assert-always-with-details.json
 // Note: Actual version info in antithesis_sdk can vary
@@ -85,7 +85,7 @@ fn assert_always_with_details() {
             let mut did_register = false;
             let mut did_hit = false;
             for obj in x.iter() {
-                if let SDKInput::AntithesisAssert(AntithesisAssert {
+                if let Record::Assertion(Assertion {
                     assert_type,
                     condition,
                     display_type,
@@ -110,7 +110,7 @@ fn assert_always_with_details() {
                     assert_eq!(message, "Waterproof Red");
                     assert_eq!(id, message);
                     assert!(location.begin_line > 0);
-                    assert!(location.begin_column >= 0);
+                    assert!(location.begin_column > 0);
                     assert_eq!(location.class, "assert_always_with_details");
                     assert!(location.function.ends_with("::assert_always_with_details"));
                     assert!(location
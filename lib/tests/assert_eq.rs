@@ -0,0 +1,62 @@
+use antithesis_sdk::testing::Capture;
+use antithesis_sdk::{assert_always_eq, assert_always_ne, assert_sometimes_eq, assert_sometimes_ne};
+use serde::Serialize;
+use serde_json::json;
+
+#[derive(Serialize, PartialEq, Debug)]
+struct Replica {
+    term: u64,
+    log: Vec<&'static str>,
+}
+
+#[test]
+fn equality_assertions() {
+    let capture = Capture::start();
+    let leader = Replica { term: 3, log: vec!["a", "b"] };
+    let follower = Replica { term: 3, log: vec!["a", "c"] };
+
+    assert_always_eq!(leader, follower, "Replicas agree", &json!({"node": 2}));
+    assert_always_ne!(leader.term, 0, "Term is set");
+    assert_sometimes_eq!(&leader.log[..1], &follower.log[..1], "Log prefixes agree");
+    assert_sometimes_ne!(id = "eq-sometimes-ne", leader.log, follower.log, ("Logs of {} diverge", "node 2"));
+
+    let agree = &capture.assertions_with_id("Replicas agree")[0];
+    assert!(!agree.condition);
+    assert_eq!(agree.details["node"], 2);
+    assert_eq!(agree.details["left"]["log"], json!(["a", "b"]));
+    assert_eq!(agree.details["diff"], json!([{"path": "/log/1", "left": "b", "right": "c"}]));
+
+    let term = &capture.assertions_with_id("Term is set")[0];
+    assert!(term.condition);
+    assert_eq!(term.details["diff"], json!([{"path": "", "left": 3, "right": 0}]));
+
+    let prefix = &capture.assertions_with_id("Log prefixes agree")[0];
+    assert!(prefix.condition);
+    assert!(prefix.details.get("diff").is_none());
+
+    let diverge = &capture.assertions_with_id("eq-sometimes-ne")[0];
+    assert!(diverge.condition);
+    assert_eq!(diverge.message, "Logs of node 2 diverge");
+}
+
+// Counts how often it is serialized, to check that operands are only serialized for emitted hits.
+#[derive(PartialEq)]
+struct Counted<'a>(&'a std::cell::Cell<u32>, u32);
+
+impl Serialize for Counted<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.set(self.0.get() + 1);
+        self.1.serialize(serializer)
+    }
+}
+
+// Outside of a capture, only the first pass is emitted: passing `ne` calls, which differ, skip the diff.
+#[test]
+fn operands_serialized_when_emitted() {
+    let serialized = std::cell::Cell::new(0);
+    for n in 0..3 {
+        assert_always_ne!(Counted(&serialized, n), Counted(&serialized, 10), "Counted operands differ");
+    }
+    // Both operands, once for the emitted hit
+    assert_eq!(serialized.get(), 2);
+}
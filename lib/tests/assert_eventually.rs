@@ -0,0 +1,64 @@
+use std::time::Duration;
+
+use antithesis_sdk::assert::eventually::{Deadline, Obligation};
+use antithesis_sdk::testing::Capture;
+use antithesis_sdk::assert_eventually;
+use serde_json::json;
+
+fn converge(deadline: Deadline) -> Obligation {
+    assert_eventually!(deadline, "Eventually converges", &json!({"nodes": 3}))
+}
+
+#[test]
+fn resolved_in_time() {
+    let capture = Capture::start();
+    let obligation = converge(Deadline::steps(5));
+    let mut lagging = 3;
+    while obligation.is_pending() {
+        lagging -= 1;
+        obligation.check(lagging == 0);
+    }
+    assert_eq!(obligation.steps(), 2);
+
+    let hits = capture.assertions_with_id("Eventually converges");
+    assert_eq!(hits.len(), 1);
+    assert!(hits[0].condition);
+    assert_eq!(hits[0].details["outcome"], "resolved");
+    assert_eq!(hits[0].details["nodes"], 3);
+    assert_eq!(hits[0].details["steps"], 2);
+    assert_eq!(hits[0].details["deadline"], json!({"steps": 5, "within_ms": null}));
+}
+
+#[test]
+fn expired() {
+    let capture = Capture::start();
+    let obligation = converge(Deadline::steps(3));
+    for _ in 0..5 {
+        obligation.step();
+    }
+    assert!(!obligation.is_pending());
+    assert_eq!(obligation.steps(), 3);
+    obligation.resolve();
+
+    let late = converge(Deadline::within(Duration::ZERO));
+    late.resolve();
+
+    let hits = capture.assertions_with_id("Eventually converges");
+    assert_eq!(hits.len(), 2);
+    assert!(hits.iter().all(|a| !a.condition && a.details["outcome"] == "expired"));
+    assert_eq!(hits[0].details["steps"], 3);
+    assert_eq!(hits[1].details["deadline"]["within_ms"], 0);
+}
+
+#[test]
+fn cancelled_and_abandoned() {
+    let capture = Capture::start();
+    converge(Deadline::steps(3)).cancel();
+    assert!(capture.assertions().is_empty());
+
+    drop(converge(Deadline::steps(3)));
+    let hits = capture.assertions_with_id("Eventually converges");
+    assert_eq!(hits.len(), 1);
+    assert!(!hits[0].condition);
+    assert_eq!(hits[0].details["outcome"], "abandoned");
+}
@@ -0,0 +1,68 @@
+use std::cell::Cell;
+
+use antithesis_sdk::testing::Capture;
+use antithesis_sdk::{assert_always, assert_always_less_than, assert_always_some, assert_sometimes, catalog};
+
+#[test]
+fn formatted_messages() {
+    let capture = Capture::start();
+    for n in 0..3 {
+        assert_sometimes!(n == 2, ("replica {} caught up", n));
+        assert_always_less_than!(n, 10, ("replica {n} is in range"), &serde_json::json!({}));
+    }
+    let id = 3;
+    assert_always!(id == 3, "Condition naming a variable id");
+    assert_always!(id = "format-with-id", id > 0, ("id is {}", id));
+
+    let hits = capture.assertions_with_id("replica {} caught up");
+    let messages: Vec<_> = hits.iter().map(|a| a.message.as_ref()).collect();
+    assert_eq!(messages, ["replica 0 caught up", "replica 1 caught up", "replica 2 caught up"]);
+
+    let hits = capture.assertions_with_id("replica {n} is in range");
+    assert_eq!(hits.len(), 3);
+    assert_eq!(hits[1].message, "replica 1 is in range");
+    let guidance = capture.guidance();
+    assert_eq!(guidance[0].id, "replica {n} is in range");
+    assert_eq!(guidance[0].message, "replica 0 is in range");
+
+    assert_eq!(capture.assertions_with_id("Condition naming a variable id").len(), 1);
+    assert_eq!(capture.assertions_with_id("format-with-id")[0].message, "id is 3");
+
+    let catalog = catalog::assertions();
+    let entry = catalog.iter().find(|a| a.id == "replica {} caught up").unwrap();
+    assert_eq!(entry.message, "replica {} caught up");
+    let entry = catalog.iter().find(|a| a.id == "format-with-id").unwrap();
+    assert_eq!(entry.message, "id is {}");
+}
+
+// Outside of a capture, a message is rendered once when its hit is emitted, and not at all otherwise,
+// even when the macro emits both an assertion and guidance.
+#[test]
+fn messages_rendered_at_most_once() {
+    let renders = Cell::new(0);
+    let tick = || {
+        renders.set(renders.get() + 1);
+        renders.get()
+    };
+
+    let mut counts = Vec::new();
+    for _ in 0..3 {
+        assert_always_less_than!(1, 2, ("lt {}", tick()));
+        counts.push(renders.replace(0));
+    }
+    // The first pass and the first guidance are emitted together, later hits are not emitted
+    assert_eq!(counts, [1, 0, 0]);
+
+    let mut counts = Vec::new();
+    for _ in 0..3 {
+        assert_always_some!({a: true, b: false}, ("some {}", tick()));
+        counts.push(renders.replace(0));
+    }
+    // Boolean guidance is emitted on every hit
+    assert_eq!(counts, [1, 1, 1]);
+
+    for _ in 0..3 {
+        assert_sometimes!(true, ("sometimes {}", tick()));
+    }
+    assert_eq!(renders.get(), 1);
+}
@@ -2,9 +2,7 @@ use antithesis_sdk::{antithesis_init, assert_always_greater_than, LOCAL_OUTPUT};
 use serde_json::json;
 
 mod common;
-use common::SDKInput;
-
-use crate::common::{AntithesisGuidance, GuidanceType};
+use antithesis_sdk::protocol::{Guidance, GuidanceType, Record};
 
 #[test]
 fn assert_guidance() {
@@ -22,7 +20,7 @@ fn assert_guidance() {
             let mut did_register = false;
             let mut did_hit = false;
             for obj in x.iter() {
-                if let SDKInput::AntithesisGuidance(AntithesisGuidance {
+                if let Record::Guidance(Guidance {
                     guidance_type,
                     hit,
                     id,
@@ -40,7 +38,7 @@ fn assert_guidance() {
                     assert_eq!(message, "Positive x");
                     assert_eq!(id, message);
                     assert!(location.begin_line > 0);
-                    assert!(location.begin_column >= 0);
+                    assert!(location.begin_column > 0);
                     assert_eq!(location.class, "assert_guidance");
                     assert!(location.function.ends_with("::assert_guidance"));
                     assert!(location
@@ -0,0 +1,44 @@
+use std::time::Duration;
+
+use antithesis_sdk::assert::GuidanceDistance;
+use antithesis_sdk::testing::Capture;
+use antithesis_sdk::{assert_always_less_than_or_equal_to, assert_sometimes_greater_than};
+use serde_json::json;
+
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+struct Term(u64);
+
+impl GuidanceDistance for Term {
+    type Measure = u64;
+
+    fn measure(&self) -> u64 {
+        self.0
+    }
+}
+
+#[test]
+fn user_defined_operands() {
+    let capture = Capture::start();
+    let budget = Duration::from_millis(5);
+    for elapsed in [Duration::from_millis(1), Duration::from_millis(3), Duration::from_millis(2)] {
+        assert_always_less_than_or_equal_to!(elapsed, budget, "Request within budget");
+    }
+    assert_sometimes_greater_than!(Term(4), Term(3), "Term advanced");
+
+    let hits = capture.assertions_with_id("Request within budget");
+    assert!(hits.iter().all(|a| a.condition));
+    assert_eq!(hits[0].details, json!({"left": 1_000_000, "right": 5_000_000}));
+
+    // Guidance minimizes the distance to the budget, so only improvements are reported.
+    let guidance: Vec<_> = capture
+        .guidance()
+        .into_iter()
+        .filter(|g| g.id == "Request within budget")
+        .map(|g| g.guidance_data["left"].clone())
+        .collect();
+    assert_eq!(guidance, [json!(1_000_000), json!(3_000_000)]);
+
+    let term = &capture.assertions_with_id("Term advanced")[0];
+    assert!(term.condition);
+    assert_eq!(term.details["left"], 4);
+}
@@ -0,0 +1,42 @@
+use antithesis_sdk::protocol::GuidanceType;
+use antithesis_sdk::testing::Capture;
+use antithesis_sdk::{assert_guidance_json, catalog};
+use serde::Serialize;
+use serde_json::json;
+
+#[derive(Serialize)]
+struct State {
+    term: u64,
+    queue_depth: usize,
+}
+
+fn report(term: u64, queue_depth: usize) {
+    assert_guidance_json!(id = "leader-state", State { term, queue_depth }, ("Leader state at term {}", term));
+}
+
+#[test]
+fn json_guidance() {
+    let capture = Capture::start();
+    for (term, depth) in [(1, 0), (1, 0), (1, 3), (2, 3), (2, 3), (1, 0)] {
+        report(term, depth);
+    }
+
+    let guidance = capture.guidance();
+    let states: Vec<_> = guidance.iter().map(|g| g.guidance_data.clone()).collect();
+    assert_eq!(
+        states,
+        [
+            json!({"term": 1, "queue_depth": 0}),
+            json!({"term": 1, "queue_depth": 3}),
+            json!({"term": 2, "queue_depth": 3}),
+            json!({"term": 1, "queue_depth": 0}),
+        ]
+    );
+    assert!(guidance.iter().all(|g| g.guidance_type == GuidanceType::Json && g.id == "leader-state" && g.hit));
+    assert_eq!(guidance[2].message, "Leader state at term 2");
+    assert!(capture.assertions().is_empty());
+
+    let entry = catalog::guidance().into_iter().find(|g| g.id == "leader-state").unwrap();
+    assert_eq!(entry.guidance_type, GuidanceType::Json);
+    assert_eq!(entry.message, "Leader state at term {}");
+}
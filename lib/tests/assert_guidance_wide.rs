@@ -0,0 +1,35 @@
+use antithesis_sdk::testing::Capture;
+use antithesis_sdk::{assert_always_less_than, assert_sometimes_greater_than};
+use serde_json::json;
+
+fn check_sequence(seq: u64, limit: u64) {
+    assert_always_less_than!(seq, limit, "Sequence below limit");
+}
+
+#[test]
+fn large_integers_are_exact() {
+    let capture = Capture::start();
+    let limit: u64 = 1 << 53;
+    // As f64, all of these distances would be the same.
+    check_sequence(limit + 1, limit);
+    check_sequence(limit + 2, limit);
+    check_sequence(limit + 2, limit);
+    check_sequence(limit + 3, limit);
+
+    let guidance = capture.guidance();
+    let lefts: Vec<_> = guidance.iter().map(|g| g.guidance_data["left"].clone()).collect();
+    assert_eq!(lefts, [json!(limit + 1), json!(limit + 2), json!(limit + 3)]);
+}
+
+#[test]
+fn wide_operands_serialize_exactly() {
+    let capture = Capture::start();
+    let big = u128::MAX - 1;
+    assert_sometimes_greater_than!(big, 7u128, "Huge value");
+
+    let assertion = &capture.assertions_with_id("Huge value")[0];
+    assert!(assertion.condition);
+    assert_eq!(assertion.details["left"], json!(big.to_string()));
+    assert_eq!(assertion.details["right"], json!(7));
+    assert_eq!(capture.guidance()[0].guidance_data["left"], json!(big.to_string()));
+}
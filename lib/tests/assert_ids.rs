@@ -0,0 +1,28 @@
+use antithesis_sdk::testing::Capture;
+use antithesis_sdk::{assert_always, assert_always_less_than, assert_reachable, assert_sometimes_all, catalog};
+
+#[test]
+fn explicit_ids() {
+    let capture = Capture::start();
+    let x = 7;
+    assert_always!(id = "ids-always", x > 0, "x is positive");
+    assert_reachable!(id = "ids-reachable", "Reached the end");
+    assert_always_less_than!(id = "ids-numeric", x, 10, "x is small");
+    assert_sometimes_all!(id = "ids-boolean", {a: true, b: x > 5}, "Both hold");
+
+    let assertions = capture.assertions();
+    let ids: Vec<_> = assertions.iter().map(|a| a.id.as_ref()).collect();
+    assert_eq!(ids, ["ids-always", "ids-reachable", "ids-numeric", "ids-boolean"]);
+    assert_eq!(assertions[0].message, "x is positive");
+    assert_eq!(assertions[2].details["left"], 7);
+
+    let guidance = capture.guidance();
+    let ids: Vec<_> = guidance.iter().map(|g| g.id.as_ref()).collect();
+    assert_eq!(ids, ["ids-numeric", "ids-boolean"]);
+    assert_eq!(guidance[0].message, "x is small");
+
+    let catalog = catalog::assertions();
+    let entry = catalog.iter().find(|a| a.id == "ids-always").unwrap();
+    assert_eq!(entry.message, "x is positive");
+    assert!(!catalog.iter().any(|a| a.id == "x is positive"));
+}
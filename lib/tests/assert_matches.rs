@@ -0,0 +1,65 @@
+use antithesis_sdk::testing::Capture;
+use antithesis_sdk::{assert_always_matches, assert_sometimes_matches};
+use serde_json::json;
+
+#[derive(Debug)]
+enum Connection {
+    Idle,
+    Busy { requests: u32 },
+    Closed(String),
+}
+
+fn check(connection: &Connection) {
+    assert_always_matches!(connection, Connection::Idle | Connection::Busy { .. }, "Connection is open", &json!({"pool": "p"}));
+    assert_sometimes_matches!(id = "busy-connection", connection, Connection::Busy { requests } if *requests > 1, ("Connection {} busy", "c"));
+    assert_sometimes_matches!(connection, Connection::Closed(reason) if reason.contains("reset"), "Connection sometimes reset");
+}
+
+#[test]
+fn matches_assertions() {
+    let capture = Capture::start();
+    check(&Connection::Idle);
+    check(&Connection::Busy { requests: 2 });
+    check(&Connection::Closed("reset by peer".to_owned()));
+
+    let open = capture.assertions_with_id("Connection is open");
+    assert_eq!(open.iter().map(|a| a.condition).collect::<Vec<_>>(), vec![true, true, false]);
+    assert_eq!(open[0].details, json!({"pool": "p"}));
+    assert_eq!(open[2].details, json!({"pool": "p", "value": "Closed(\"reset by peer\")"}));
+
+    let busy = capture.assertions_with_id("busy-connection");
+    assert_eq!(busy.iter().map(|a| a.condition).collect::<Vec<_>>(), vec![false, true, false]);
+    assert_eq!(busy[0].details, json!({"value": "Idle"}));
+    assert_eq!(busy[1].message, "Connection c busy");
+
+    let reset = capture.assertions_with_id("Connection sometimes reset");
+    assert_eq!(reset.iter().map(|a| a.condition).collect::<Vec<_>>(), vec![false, false, true]);
+}
+
+#[test]
+fn matches_by_value() {
+    let capture = Capture::start();
+    let reply: Result<u32, String> = Err("timeout".to_owned());
+    assert_always_matches!(reply, Ok(_), "Replies succeed");
+    assert_eq!(capture.assertions_with_id("Replies succeed")[0].details["value"], "Err(\"timeout\")");
+    assert_eq!(reply, Err("timeout".to_owned()));
+}
+
+// Counts how often it is rendered, to check that mismatches are only rendered for emitted hits.
+struct Counted<'a>(&'a std::cell::Cell<u32>);
+
+impl std::fmt::Debug for Counted<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.set(self.0.get() + 1);
+        write!(f, "Counted")
+    }
+}
+
+#[test]
+fn mismatch_rendered_when_emitted() {
+    let renders = std::cell::Cell::new(0);
+    for _ in 0..3 {
+        assert_always_matches!(Some(Counted(&renders)), None, "Counted values are rendered once");
+    }
+    assert_eq!(renders.get(), 1);
+}
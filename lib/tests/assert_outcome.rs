@@ -0,0 +1,106 @@
+use std::error::Error;
+use std::fmt;
+
+use antithesis_sdk::testing::Capture;
+use antithesis_sdk::{
+    assert_always_ok, assert_always_some_value, assert_sometimes_err, assert_sometimes_none, assert_sometimes_ok,
+    assert_sometimes_some_value,
+};
+use serde_json::json;
+
+#[derive(Debug)]
+enum StoreError {
+    Unavailable(std::io::Error),
+}
+
+impl fmt::Display for StoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "store unavailable")
+    }
+}
+
+impl Error for StoreError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            StoreError::Unavailable(e) => Some(e),
+        }
+    }
+}
+
+fn write(fail: bool) -> Result<u64, StoreError> {
+    if fail {
+        Err(StoreError::Unavailable(std::io::Error::new(std::io::ErrorKind::Other, "disk full")))
+    } else {
+        Ok(7)
+    }
+}
+
+#[test]
+fn result_assertions() {
+    let capture = Capture::start();
+    let written = assert_always_ok!(write(false), "Writes succeed", &json!({"table": "t"}));
+    assert_eq!(written.unwrap(), 7);
+    let failed = assert_always_ok!(write(true), "Writes succeed", &json!({"table": "t"}));
+    assert!(failed.is_err());
+
+    let hits = capture.assertions_with_id("Writes succeed");
+    assert_eq!(hits.len(), 2);
+    assert!(hits[0].condition);
+    assert_eq!(hits[0].details, json!({"table": "t"}));
+    assert!(!hits[1].condition);
+    assert_eq!(hits[1].details["table"], "t");
+    assert_eq!(hits[1].details["error"]["display"], "store unavailable");
+    assert_eq!(hits[1].details["error"]["sources"], json!(["disk full"]));
+
+    let parsed = assert_sometimes_ok!(id = "parse-port", "x".parse::<u16>(), ("Port {} parses", "x"));
+    assert!(parsed.is_err());
+    let hit = &capture.assertions_with_id("parse-port")[0];
+    assert!(!hit.condition);
+    assert_eq!(hit.details["error"]["display"], "invalid digit found in string");
+
+    let rejected: Result<(), (u16, &str)> = Err((409, "conflict"));
+    let _ = assert_sometimes_err!(rejected, "Writes are sometimes rejected");
+    let hit = &capture.assertions_with_id("Writes are sometimes rejected")[0];
+    assert!(hit.condition);
+    assert_eq!(hit.details, json!({"error": {"debug": "(409, \"conflict\")"}}));
+}
+
+#[test]
+fn option_assertions() {
+    let capture = Capture::start();
+    let leaders = [Some("a"), None];
+    for leader in leaders {
+        let _ = assert_always_some_value!(leader, "Shard has a leader");
+        let _ = assert_sometimes_some_value!(leader, "Leader sometimes known");
+        assert_eq!(assert_sometimes_none!(leader, "Leader sometimes unknown"), leader);
+    }
+
+    let conditions = |id: &str| capture.assertions_with_id(id).iter().map(|a| a.condition).collect::<Vec<_>>();
+    assert_eq!(conditions("Shard has a leader"), vec![true, false]);
+    assert_eq!(conditions("Leader sometimes known"), vec![true, false]);
+    assert_eq!(conditions("Leader sometimes unknown"), vec![false, true]);
+}
+
+// Counts how often it is described, to check that errors are only captured for emitted hits.
+#[derive(Debug)]
+struct Counted<'a>(&'a std::cell::Cell<u32>);
+
+impl fmt::Display for Counted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.set(self.0.get() + 1);
+        write!(f, "counted")
+    }
+}
+
+impl Error for Counted<'_> {}
+
+// Outside of a capture, only the first failure is emitted, so the error is described once.
+#[test]
+fn errors_captured_when_emitted() {
+    let displays = std::cell::Cell::new(0);
+    for _ in 0..3 {
+        let result: Result<(), Counted> = Err(Counted(&displays));
+        let _ = assert_always_ok!(result, "Counted errors are described once");
+    }
+    assert_eq!(displays.get(), 1);
+}
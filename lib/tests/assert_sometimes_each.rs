@@ -0,0 +1,67 @@
+use antithesis_sdk::assert::each::KEY_LIMIT_EVENT;
+use antithesis_sdk::testing::Capture;
+use antithesis_sdk::{assert_sometimes_each, catalog};
+use serde_json::json;
+
+fn observe(shard: u32, degraded: bool) {
+    assert_sometimes_each!(shard, degraded, "Shard observed degraded", &json!({"node": 1}));
+}
+
+#[test]
+fn property_per_key() {
+    let capture = Capture::start();
+    observe(1, false);
+    observe(2, true);
+    observe(1, true);
+
+    let group = capture.assertions_with_id("Shard observed degraded");
+    assert_eq!(group.len(), 3);
+    assert_eq!(group[1].details, json!({"node": 1, "key": 2}));
+
+    let shard_1 = capture.assertions_with_id("Shard observed degraded [1]");
+    assert_eq!(shard_1.len(), 3);
+    assert!(!shard_1[0].hit);
+    assert!(shard_1[0].must_hit);
+    assert!(shard_1[1].hit && !shard_1[1].condition);
+    assert!(shard_1[2].hit && shard_1[2].condition);
+    assert_eq!(shard_1[2].message, "Shard observed degraded [1]");
+
+    let shard_2 = capture.assertions_with_id("Shard observed degraded [2]");
+    assert_eq!(shard_2.len(), 2);
+    assert!(shard_2[1].condition);
+
+    assert!(catalog::assertions().iter().any(|a| a.id == "Shard observed degraded"));
+}
+
+#[test]
+fn key_limit() {
+    let capture = Capture::start();
+    for region in ["us-east", "eu-west", "ap-south", "us-east"] {
+        assert_sometimes_each!(id = "region-traffic", max_keys = 2, region, true, ("Region {} served traffic", "x"));
+    }
+
+    assert_eq!(capture.assertions_with_id("region-traffic").len(), 4);
+    assert_eq!(capture.assertions_with_id("region-traffic [us-east]").len(), 3);
+    assert_eq!(capture.assertions_with_id("region-traffic [eu-west]").len(), 2);
+    assert!(capture.assertions_with_id("region-traffic [ap-south]").is_empty());
+
+    let events: Vec<_> = capture.events().into_iter().filter(|e| e.name == KEY_LIMIT_EVENT).collect();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].details, json!({"id": "region-traffic", "max_keys": 2, "dropped_key": "ap-south"}));
+}
+
+// Outside of a capture, hits of keys that are already tracked are not emitted, and skip rendering the message.
+#[test]
+fn tracked_keys_skip_rendering() {
+    let renders = std::cell::Cell::new(0);
+    let tick = || {
+        renders.set(renders.get() + 1);
+        renders.get()
+    };
+    for _ in 0..3 {
+        assert_sometimes_each!("shard-a", true, ("Shard {} answered", tick()));
+    }
+    assert_eq!(renders.get(), 1);
+    assert_sometimes_each!("shard-b", true, ("Shard {} answered", tick()));
+    assert_eq!(renders.get(), 2);
+}
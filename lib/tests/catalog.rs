@@ -0,0 +1,59 @@
+use std::env;
+use std::process::Command;
+
+use antithesis_sdk::protocol::AssertType;
+use antithesis_sdk::{antithesis_init, assert_always_greater_than, assert_reachable, catalog};
+use serde_json::Value;
+
+#[allow(dead_code)]
+fn never_called(x: u32) {
+    assert_reachable!("Catalog: never reached");
+    assert_always_greater_than!(x, 5, "Catalog: x is large");
+}
+
+#[test]
+fn lists_unreached_assertions() {
+    let assertions = catalog::assertions();
+    let reachable = assertions
+        .iter()
+        .find(|a| a.id == "Catalog: never reached")
+        .unwrap();
+    assert_eq!(reachable.assert_type, AssertType::Reachability);
+    assert_eq!(reachable.display_type, "Reachable");
+    assert!(!reachable.hit);
+    assert!(reachable.must_hit);
+    assert!(reachable.location.function.ends_with("never_called"));
+
+    let ids: Vec<_> = assertions.iter().map(|a| a.id.clone()).collect();
+    let mut sorted = ids.clone();
+    sorted.sort();
+    assert_eq!(ids, sorted);
+
+    let guidance = catalog::guidance();
+    assert!(guidance.iter().any(|g| g.id == "Catalog: x is large" && !g.maximize));
+}
+
+#[test]
+fn dump_on_init() {
+    if env::var_os(catalog::DUMP_CATALOG).is_some() {
+        antithesis_init();
+        unreachable!("antithesis_init() should have exited");
+    }
+    let output = Command::new(env::current_exe().unwrap())
+        .args(["--exact", "dump_on_init", "--nocapture", "--test-threads", "1"])
+        .env(catalog::DUMP_CATALOG, "1")
+        .env_remove("ANTITHESIS_SDK_LOCAL_OUTPUT")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let start = stdout.find('{').unwrap();
+    let end = stdout.rfind('}').unwrap();
+    let dump: Value = serde_json::from_str(&stdout[start..=end]).unwrap();
+    assert!(dump["assertions"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|a| a["id"] == "Catalog: x is large" && a["hit"] == false));
+    assert_eq!(dump, serde_json::from_str::<Value>(&catalog::dump_json()).unwrap());
+}
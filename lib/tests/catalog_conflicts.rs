@@ -0,0 +1,62 @@
+use std::env;
+use std::process::Command;
+
+use antithesis_sdk::protocol::{AssertType, Record};
+use antithesis_sdk::{antithesis_init, assert_always, assert_reachable, assert_sometimes, assert_unreachable, catalog, LOCAL_OUTPUT};
+
+mod common;
+
+#[allow(dead_code)]
+fn properties(x: u32) {
+    assert_always!(x > 0, "Conflict: copy-pasted");
+    assert_sometimes!(x > 10, "Conflict: copy-pasted");
+    assert_reachable!("Conflict: reached twice");
+    assert_reachable!("Conflict: reached twice");
+    assert_reachable!("Conflict: must_hit differs");
+    assert_unreachable!("Conflict: must_hit differs");
+}
+
+#[test]
+fn conflicts_and_events() {
+    let conflicts = catalog::conflicts();
+    let ids: Vec<_> = conflicts.iter().map(|c| c.id.as_str()).collect();
+    assert_eq!(ids, ["Conflict: copy-pasted", "Conflict: must_hit differs"]);
+    let types: Vec<_> = conflicts[0].assertions.iter().map(|a| a.assert_type).collect();
+    assert!(types.contains(&AssertType::Always) && types.contains(&AssertType::Sometimes));
+    assert!(conflicts[0].to_string().contains("Always"));
+    assert!(std::ptr::eq(conflicts, catalog::conflicts()));
+
+    let output_file = "/tmp/antithesis-catalog-conflicts.json";
+    let prev_v = common::env::set_var(LOCAL_OUTPUT, output_file);
+    antithesis_init();
+    let records = common::read_jsonl_tags(output_file).unwrap();
+    let events: Vec<_> = records
+        .iter()
+        .filter_map(|record| match record {
+            Record::Event(event) if event.name == catalog::CONFLICT_EVENT => Some(&event.details),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0]["id"], "Conflict: copy-pasted");
+    assert_eq!(events[0]["assertions"].as_array().unwrap().len(), 2);
+    common::env::restore_var(LOCAL_OUTPUT, prev_v);
+}
+
+#[test]
+fn strict_mode_panics() {
+    if env::var_os(catalog::STRICT_CATALOG).is_some() {
+        antithesis_init();
+        return;
+    }
+    let output = Command::new(env::current_exe().unwrap())
+        .args(["--exact", "strict_mode_panics", "--test-threads", "1"])
+        .env(catalog::STRICT_CATALOG, "1")
+        .env_remove(LOCAL_OUTPUT)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stdout.contains("Conflict: copy-pasted") || stderr.contains("Conflict: copy-pasted"));
+}
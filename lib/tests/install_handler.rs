@@ -0,0 +1,53 @@
+use std::io::Error;
+use std::sync::{Arc, Mutex};
+
+use antithesis_sdk::handler::{self, Handler};
+use antithesis_sdk::{antithesis_init, assert_sometimes, lifecycle, random};
+use serde_json::{json, Value};
+
+struct RecordingHandler {
+    records: Arc<Mutex<Vec<String>>>,
+}
+
+impl Handler for RecordingHandler {
+    fn output(&self, value: &str) -> Result<(), Error> {
+        self.records.lock().unwrap().push(value.to_owned());
+        Ok(())
+    }
+
+    fn random(&self) -> u64 {
+        42
+    }
+}
+
+#[test]
+fn install_handler() {
+    let records = Arc::new(Mutex::new(Vec::new()));
+    let recorder = RecordingHandler {
+        records: records.clone(),
+    };
+    assert!(handler::install_handler(Box::new(recorder)).is_ok());
+    antithesis_init();
+
+    // Once the SDK is initialized, the handler can no longer be replaced
+    let late = RecordingHandler {
+        records: Arc::new(Mutex::new(Vec::new())),
+    };
+    assert!(handler::install_handler(Box::new(late)).is_err());
+
+    assert_eq!(random::get_random(), 42);
+    assert_sometimes!(true, "Routed to custom handler", &json!({"x": 1}));
+    lifecycle::send_event("custom_event", &json!({"y": 2}));
+    handler::flush();
+
+    let parsed: Vec<Value> = records
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert!(parsed[0].get("antithesis_sdk").is_some());
+    assert!(parsed.iter().any(|v| v["antithesis_assert"]["id"] == "Routed to custom handler"
+        && v["antithesis_assert"]["hit"] == true));
+    assert!(parsed.iter().any(|v| v["custom_event"]["y"] == 2));
+}
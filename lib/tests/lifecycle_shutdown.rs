@@ -0,0 +1,21 @@
+use antithesis_sdk::assert::eventually::Deadline;
+use antithesis_sdk::testing::Capture;
+use antithesis_sdk::{assert_eventually, catalog, lifecycle};
+
+// Shutdown settles the obligations of every thread, so this test has its own binary.
+#[test]
+fn unresolved_at_shutdown() {
+    let capture = Capture::start();
+    let obligation = assert_eventually!(id = "eventually-shutdown", Deadline::steps(10), "Resolved before shutdown");
+    lifecycle::shutdown();
+    assert!(!obligation.is_pending());
+
+    let hits = capture.assertions_with_id("eventually-shutdown");
+    assert_eq!(hits.len(), 1);
+    assert!(!hits[0].condition);
+    assert_eq!(hits[0].details["outcome"], "unresolved at shutdown");
+
+    let entry = catalog::assertions().into_iter().find(|a| a.id == "eventually-shutdown").unwrap();
+    assert!(entry.must_hit);
+    assert_eq!(entry.display_type, "Always");
+}
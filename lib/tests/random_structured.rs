@@ -0,0 +1,88 @@
+use std::io::Error;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use antithesis_sdk::handler::{self, Handler, RandomRequest};
+use antithesis_sdk::random;
+
+// Chooses the last value of each bound, true when it is at least as likely as false,
+// 0.75 for floats and 0 for wide values, and records the requests it received.
+struct StructuredHandler {
+    requests: Arc<Mutex<Vec<RandomRequest>>>,
+}
+
+impl Handler for StructuredHandler {
+    fn output(&self, _value: &str) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn random(&self) -> u64 {
+        u64::MAX
+    }
+
+    fn structured_random(&self, request: &RandomRequest) -> u64 {
+        self.requests.lock().unwrap().push(*request);
+        match *request {
+            RandomRequest::Below { bound } => bound - 1,
+            RandomRequest::Bool { probability } if probability >= 0.5 => 0,
+            RandomRequest::Bool { .. } => u64::MAX,
+            RandomRequest::Unit => 3 << 62,
+            _ => 0,
+        }
+    }
+}
+
+#[test]
+fn structured_requests() {
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let structured = StructuredHandler {
+        requests: requests.clone(),
+    };
+    assert!(handler::install_handler(Box::new(structured)).is_ok());
+
+    assert_eq!(random::range(10u16..20), 19);
+    assert_eq!(random::range(-3i32..=3), 3);
+    assert!(!random::bool_with_probability(0.25));
+    assert!(random::bool_with_probability(0.5));
+    assert_eq!(random::f64_unit(), 0.75);
+    assert_eq!(
+        random::duration_between(Duration::from_secs(1), Duration::from_secs(2)),
+        Duration::from_secs(2)
+    );
+    assert_eq!(random::weighted_choice(&[("read", 5), ("write", 0), ("admin", 1)]), Some(&"admin"));
+    assert_eq!(random::random_choice(&["a", "b", "c", "d"]), Some(&"d"));
+
+    let mut order = [1, 2, 3];
+    random::shuffle(&mut order);
+    assert_eq!(order, [1, 2, 3]);
+    assert_eq!(random::sample(&["a", "b", "c"], 2), vec![&"c", &"a"]);
+    assert_eq!(random::subset(&[1, 2]), vec![&1, &2]);
+    assert_eq!(random::range(0..=u64::MAX), 0);
+    assert_eq!(random::range(5u128..=(1 << 64) + 5), 5);
+    assert_eq!(random::range::<i128, _>(..), i128::MIN);
+
+    assert_eq!(
+        *requests.lock().unwrap(),
+        vec![
+            RandomRequest::Below { bound: 10 },
+            RandomRequest::Below { bound: 7 },
+            RandomRequest::Bool { probability: 0.25 },
+            RandomRequest::Bool { probability: 0.5 },
+            RandomRequest::Unit,
+            RandomRequest::Below { bound: 1_000_000_001 },
+            RandomRequest::Below { bound: 6 },
+            RandomRequest::Below { bound: 4 },
+            RandomRequest::Below { bound: 3 },
+            RandomRequest::Below { bound: 2 },
+            RandomRequest::Below { bound: 3 },
+            RandomRequest::Below { bound: 2 },
+            RandomRequest::Bool { probability: 0.5 },
+            RandomRequest::Bool { probability: 0.5 },
+            RandomRequest::Wide { max: u64::MAX as u128 },
+            RandomRequest::Wide { max: 1 << 64 },
+            RandomRequest::Wide { max: 1 << 64 },
+            RandomRequest::Wide { max: u128::MAX },
+            RandomRequest::Wide { max: u128::MAX },
+        ]
+    );
+}
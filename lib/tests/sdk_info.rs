@@ -2,7 +2,7 @@ use antithesis_sdk::{lifecycle, LOCAL_OUTPUT};
 use serde_json::json;
 
 mod common;
-use common::SDKInput;
+use antithesis_sdk::protocol::{Record, PROTOCOL_VERSION};
 
 // Expected output in /tmp/antithesis-sdk-info.json
 // Note: Actual version info in antithesis_sdk can vary
@@ -37,8 +37,9 @@ fn sdk_info() {
     match common::read_jsonl_tags(output_file) {
         Ok(x) => {
             for obj in x.iter() {
-                if let SDKInput::AntithesisSdk(sdk) = obj {
+                if let Record::Sdk(sdk) = obj {
                     assert_eq!(sdk.protocol_version, "1.1.0");
+                    assert_eq!(sdk.protocol_version, PROTOCOL_VERSION);
                     assert_eq!(sdk.language.name, "Rust")
                 }
             }
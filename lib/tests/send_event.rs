@@ -2,7 +2,7 @@ use antithesis_sdk::{lifecycle, LOCAL_OUTPUT};
 use serde_json::json;
 
 mod common;
-use common::SDKInput;
+use antithesis_sdk::protocol::{Event, Record};
 
 // Expected output in /tmp/antithesis-send-event.json
 // Note: Actual version info in antithesis_sdk can vary
@@ -40,12 +40,8 @@ fn send_event() {
     match common::read_jsonl_tags(output_file) {
         Ok(x) => {
             for obj in x.iter() {
-                if let SDKInput::SendEvent {
-                    event_name,
-                    details,
-                } = obj
-                {
-                    assert_eq!(event_name, "logging");
+                if let Record::Event(Event { name, details }) = obj {
+                    assert_eq!(name, "logging");
                     assert_eq!(&details["x"], 100);
                     assert_eq!(&details["tag"], "last value");
                 }
@@ -2,7 +2,7 @@ use antithesis_sdk::{lifecycle, LOCAL_OUTPUT};
 use serde_json::{json, Value};
 
 mod common;
-use common::{AntithesisSetup, SDKInput};
+use antithesis_sdk::protocol::{Record, Setup};
 
 // Expected output in /tmp/antithesis-lifecycle-with-details.json
 // Note: Actual version info in antithesis_sdk can vary
@@ -47,7 +47,7 @@ fn setup_complete_with_details() {
     match common::read_jsonl_tags(output_file) {
         Ok(x) => {
             for obj in x.iter() {
-                if let SDKInput::AntithesisSetup(AntithesisSetup { status, details }) = obj {
+                if let Record::Setup(Setup { status, details }) = obj {
                     assert_eq!(status, "complete");
                     assert_eq!(details, &bird_value)
                 }
@@ -0,0 +1,66 @@
+use antithesis_sdk::protocol::Record;
+use antithesis_sdk::testing::{self, Capture};
+use antithesis_sdk::assert::AssertType;
+use antithesis_sdk::{assert_always, assert_always_greater_than, assert_sometimes, lifecycle};
+use serde_json::json;
+
+// Both tests hit the same properties with different conditions.
+// They run on separate threads, so each capture only sees its own hits.
+
+#[test]
+fn capture_passing_hits() {
+    let capture = Capture::start();
+    for _ in 0..3 {
+        assert_always!(true, "Shared always", &json!({"side": "passing"}));
+        assert_sometimes!(true, "Shared sometimes");
+    }
+
+    let always = capture.assertions_with_id("Shared always");
+    assert_eq!(always.len(), 3);
+    assert!(always.iter().all(|a| a.hit && a.condition));
+    assert_eq!(always[0].assert_type, AssertType::Always);
+    assert_eq!(always[0].details, json!({"side": "passing"}));
+    assert!(always[0].location.file.ends_with("tests/testing_capture.rs"));
+    assert!(capture
+        .assertions_with_id("Shared sometimes")
+        .iter()
+        .all(|a| a.condition));
+}
+
+#[test]
+fn capture_failing_hits() {
+    let capture = Capture::start();
+    assert_always!(false, "Shared always", &json!({"side": "failing"}));
+    assert_sometimes!(false, "Shared sometimes");
+
+    let assertions = capture.assertions();
+    assert_eq!(assertions.len(), 2);
+    assert!(assertions.iter().all(|a| a.hit && !a.condition));
+}
+
+#[test]
+fn capture_guidance_setup_and_events() {
+    let ((), records) = testing::capture(|| {
+        assert_always_greater_than!(1, 2, "Capture guidance");
+        lifecycle::setup_complete(&json!({"nodes": 3}));
+        lifecycle::send_event("captured", &json!({"x": 1}));
+    });
+
+    assert!(records.iter().any(|r| matches!(r, Record::Guidance(g) if g.id == "Capture guidance")));
+    assert!(records.iter().any(|r| matches!(r, Record::Setup(s) if s.details == json!({"nodes": 3}))));
+    assert!(records.iter().any(|r| matches!(r, Record::Event(e) if e.name == "captured")));
+}
+
+#[test]
+fn nested_captures() {
+    let outer = Capture::start();
+    {
+        let inner = Capture::start();
+        lifecycle::send_event("inner", &json!({}));
+        assert_eq!(inner.events().len(), 1);
+    }
+    lifecycle::send_event("outer", &json!({}));
+    let events = outer.events();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].name, "outer");
+}
@@ -0,0 +1,272 @@
+//! Attribute and derive macros for the [Antithesis Rust SDK](https://docs.rs/antithesis_sdk).
+//!
+//! These macros are re-exported by `antithesis_sdk` when its `macros` feature is enabled,
+//! and expand to calls into `antithesis_sdk`, which must be a dependency of the crate using them.
+//! Each attribute takes the ``message`` of its assertion, and optionally a leading ``id = "..."``,
+//! as the assertion macros do.
+//!
+//! Assertions placed by these attributes are reported in the function they annotate, named by
+//! its path, including the type of methods. Unlike the assertion macros, this also names async
+//! functions correctly, rather than after the future they return.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, quote_spanned};
+use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, DeriveInput, Expr, Ident, ItemFn, LitStr, ReturnType, Token};
+
+/// Assert that the annotated function is called at least once, as
+/// [`assert_reachable!`](https://docs.rs/antithesis_sdk/latest/antithesis_sdk/macro.assert_reachable.html) does at the top of its body.
+///
+/// # Example
+///
+/// ```
+/// #[antithesis_sdk::reachable("Leader election starts")]
+/// fn start_election(term: u64) -> u64 {
+///     term + 1
+/// }
+/// # start_election(1);
+/// ```
+#[proc_macro_attribute]
+pub fn reachable(args: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args as Args);
+    let item = parse_macro_input!(item as ItemFn);
+    prepend(args, item, quote!(true), "Reachable", quote!(true))
+}
+
+/// Assert that the annotated function is never called, as
+/// [`assert_unreachable!`](https://docs.rs/antithesis_sdk/latest/antithesis_sdk/macro.assert_unreachable.html) does at the top of its body.
+///
+/// # Example
+///
+/// ```
+/// #[antithesis_sdk::unreachable(id = "split-brain", "Two leaders elected for a term")]
+/// fn step_down(term: u64) -> u64 {
+///     term
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn unreachable(args: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args as Args);
+    let item = parse_macro_input!(item as ItemFn);
+    prepend(args, item, quote!(false), "Unreachable", quote!(false))
+}
+
+/// Assert that the annotated function returns ``Ok`` every time it is called, **and** that it is
+/// called at least once, as [`assert_always_ok!`](https://docs.rs/antithesis_sdk/latest/antithesis_sdk/macro.assert_always_ok.html)
+/// does on its result. Returning early, including with ``?``, is checked as well.
+///
+/// # Example
+///
+/// ```
+/// #[antithesis_sdk::always_returns_ok("Snapshots are written")]
+/// async fn write_snapshot(path: &str) -> Result<usize, std::io::Error> {
+///     let len = path.len();
+///     Ok(len)
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn always_returns_ok(args: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args as Args);
+    let mut item = parse_macro_input!(item as ItemFn);
+    if let ReturnType::Default = item.sig.output {
+        return syn::Error::new(item.sig.span(), "`always_returns_ok` expects a function returning a `Result`")
+            .to_compile_error()
+            .into();
+    }
+    let block = &item.block;
+    // Run the body on its own, so that early returns are checked too. The
+    // closure must be `FnOnce` to return borrows of the arguments it captures.
+    let result = if item.sig.asyncness.is_some() {
+        quote!(async move #block.await)
+    } else {
+        quote!({
+            fn call_once<R>(body: impl ::std::ops::FnOnce() -> R) -> R {
+                body()
+            }
+            call_once(move || #block)
+        })
+    };
+    let (function, marker) = function_path();
+    let (id, message) = (args.id(), &args.message);
+    item.block = syn::parse_quote!({
+        #marker
+        let result = #result;
+        ::antithesis_sdk::result_helper!(
+            function = #function,
+            id = #id,
+            expect_ok = true,
+            result,
+            #message,
+            ,
+            ::antithesis_sdk::assert::AssertType::Always,
+            "Always",
+            must_hit = true
+        )
+    });
+    quote!(#item).into()
+}
+
+/// Derives an ``assert_invariants(&self)`` method, which asserts each ``#[invariant(condition, "message")]``
+/// declared on the type as [`assert_always!`](https://docs.rs/antithesis_sdk/latest/antithesis_sdk/macro.assert_always.html) does.
+/// The ``condition`` can refer to the value as ``self``, and each invariant can be given an ``id = "..."`` first.
+///
+/// When the type implements ``Serialize``, the value is added to the details of each assertion.
+///
+/// # Example
+///
+/// ```
+/// use antithesis_sdk::AntithesisInvariants;
+/// use serde::Serialize;
+///
+/// #[derive(AntithesisInvariants, Serialize)]
+/// #[invariant(self.len <= self.capacity, "Buffer length within capacity")]
+/// #[invariant(id = "buffer-nonempty-capacity", self.capacity > 0, "Buffer has capacity")]
+/// struct Buffer {
+///     len: usize,
+///     capacity: usize,
+/// }
+///
+/// let mut buffer = Buffer { len: 0, capacity: 4 };
+/// buffer.len += 1;
+/// buffer.assert_invariants();
+/// ```
+#[proc_macro_derive(AntithesisInvariants, attributes(invariant))]
+pub fn derive_invariants(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let function = format!("::{}::assert_invariants", name);
+    let mut assertions = Vec::new();
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("invariant")) {
+        let Invariant { id, condition, message } = match attr.parse_args() {
+            Ok(invariant) => invariant,
+            Err(e) => return e.to_compile_error().into(),
+        };
+        let id = id.as_ref().unwrap_or(&message);
+        // Located at the attribute, so that each invariant is reported on its own line
+        assertions.push(quote_spanned! {attr.span()=>
+            ::antithesis_sdk::assert_helper!(
+                function = ::std::concat!(::std::module_path!(), #function),
+                condition = #condition,
+                id = #id,
+                #message,
+                details = &details,
+                ::antithesis_sdk::assert::AssertType::Always,
+                "Always",
+                must_hit = true
+            );
+        });
+    }
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Asserts every invariant declared with `#[invariant(condition, "message")]`.
+            pub fn assert_invariants(&self) {
+                #[allow(unused_imports)]
+                use ::antithesis_sdk::assert::invariants::{NoSnapshot as _, SerializeSnapshot as _};
+                let details = (&&::antithesis_sdk::assert::invariants::Snapshot(self)).snapshot();
+                #(#assertions)*
+            }
+        }
+    }
+    .into()
+}
+
+// An optional ``id = "..."``, then the message.
+struct Args {
+    id: Option<LitStr>,
+    message: LitStr,
+}
+
+impl Args {
+    fn id(&self) -> &LitStr {
+        self.id.as_ref().unwrap_or(&self.message)
+    }
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut id = None;
+        if input.peek(Ident) && input.peek2(Token![=]) {
+            let key: Ident = input.parse()?;
+            if key != "id" {
+                return Err(syn::Error::new(key.span(), "expected `id = \"...\"` or a message"));
+            }
+            input.parse::<Token![=]>()?;
+            id = Some(input.parse()?);
+            input.parse::<Token![,]>()?;
+        }
+        let message = input.parse()?;
+        if !input.is_empty() {
+            return Err(input.error("expected only a message, e.g. `#[reachable(\"message\")]`"));
+        }
+        Ok(Args { id, message })
+    }
+}
+
+// The path of the annotated function, and the item that must be declared in its body to find it.
+// The path is taken from the ``type_name`` of that item, as only the compiler knows the type of
+// the impl block of a method.
+fn function_path() -> (TokenStream2, TokenStream2) {
+    let marker = quote!(
+        #[allow(dead_code)]
+        fn __antithesis_function() {}
+    );
+    let function = quote!(::antithesis_sdk::assert::enclosing_function(__antithesis_function));
+    (function, marker)
+}
+
+// Inserts an assertion at the top of the function body.
+fn prepend(args: Args, mut item: ItemFn, condition: TokenStream2, display_type: &str, must_hit: TokenStream2) -> TokenStream {
+    let (function, marker) = function_path();
+    let (id, message) = (args.id(), &args.message);
+    let block = &item.block;
+    let assertion = quote_spanned! {message.span()=>
+        ::antithesis_sdk::assert_helper!(
+            function = #function,
+            condition = #condition,
+            id = #id,
+            #message,
+            ,
+            ::antithesis_sdk::assert::AssertType::Reachability,
+            #display_type,
+            must_hit = #must_hit
+        );
+    };
+    item.block = syn::parse_quote!({
+        #marker
+        #assertion
+        #block
+    });
+    quote!(#item).into()
+}
+
+// An optional ``id = "..."``, the condition, then the message.
+struct Invariant {
+    id: Option<LitStr>,
+    condition: Expr,
+    message: LitStr,
+}
+
+impl Parse for Invariant {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut id = None;
+        if input.peek(Ident) && input.peek2(Token![=]) && !input.peek2(Token![==]) {
+            let key: Ident = input.parse()?;
+            if key != "id" {
+                return Err(syn::Error::new(key.span(), "expected `id = \"...\"` or a condition"));
+            }
+            input.parse::<Token![=]>()?;
+            id = Some(input.parse()?);
+            input.parse::<Token![,]>()?;
+        }
+        let condition = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let message = input.parse()?;
+        if !input.is_empty() {
+            return Err(input.error("expected `#[invariant(condition, \"message\")]`"));
+        }
+        Ok(Invariant { id, condition, message })
+    }
+}
@@ -0,0 +1,126 @@
+use std::fmt;
+
+use antithesis_sdk::testing::Capture;
+use antithesis_sdk::{always_returns_ok, catalog, reachable};
+use serde_json::json;
+
+#[derive(Debug)]
+struct Rejected;
+
+impl fmt::Display for Rejected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "rejected")
+    }
+}
+
+impl std::error::Error for Rejected {}
+
+#[reachable("Election started")]
+fn start_election(term: u64) -> u64 {
+    term + 1
+}
+
+#[antithesis_sdk::unreachable(id = "stale-term", "Vote granted for a stale term")]
+fn grant_stale_vote() {}
+
+#[always_returns_ok("Votes are counted")]
+fn count_votes(votes: &[bool]) -> Result<usize, Rejected> {
+    if votes.is_empty() {
+        return Err(Rejected);
+    }
+    let granted = votes.iter().filter(|v| **v).count();
+    Ok(granted)
+}
+
+struct Log {
+    entries: Vec<u64>,
+}
+
+trait Replicate {
+    fn append(&mut self, entry: u64) -> Result<&u64, Rejected>;
+}
+
+impl Replicate for Log {
+    #[always_returns_ok(id = "log-append", "Entries are appended")]
+    fn append(&mut self, entry: u64) -> Result<&u64, Rejected> {
+        self.entries.push(entry);
+        self.entries.last().ok_or(Rejected)
+    }
+}
+
+impl Log {
+    #[reachable("Log compacted")]
+    fn compact(&mut self) {
+        self.entries.clear();
+    }
+}
+
+#[always_returns_ok("Snapshots are installed")]
+async fn install_snapshot(ok: bool) -> Result<(), Rejected> {
+    if !ok {
+        Err(Rejected)?;
+    }
+    Ok(())
+}
+
+// Polls a future that is ready without waiting.
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+    fn raw() -> RawWaker {
+        RawWaker::new(std::ptr::null(), &VTABLE)
+    }
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(|_| raw(), |_| {}, |_| {}, |_| {});
+    let waker = unsafe { Waker::from_raw(raw()) };
+    let mut future = Box::pin(future);
+    match future.as_mut().poll(&mut Context::from_waker(&waker)) {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("future is not ready"),
+    }
+}
+
+#[test]
+fn reachability_attributes() {
+    let capture = Capture::start();
+    assert_eq!(start_election(1), 2);
+    if start_election(0) == 0 {
+        grant_stale_vote();
+    }
+
+    let hits = capture.assertions_with_id("Election started");
+    assert_eq!(hits.len(), 2);
+    assert!(hits[0].condition);
+    assert_eq!(hits[0].location.function, "attributes::start_election");
+
+    let entry = catalog::assertions().into_iter().find(|a| a.id == "stale-term").unwrap();
+    assert_eq!(entry.display_type, "Unreachable");
+    assert_eq!(entry.location.function, "attributes::grant_stale_vote");
+    assert!(!entry.must_hit);
+}
+
+#[test]
+fn returns_ok_attribute() {
+    let capture = Capture::start();
+    assert_eq!(count_votes(&[true, false, true]).unwrap(), 2);
+    assert!(count_votes(&[]).is_err());
+
+    let hits = capture.assertions_with_id("Votes are counted");
+    assert_eq!(hits.iter().map(|a| a.condition).collect::<Vec<_>>(), vec![true, false]);
+    assert_eq!(hits[1].details["error"]["display"], "rejected");
+    assert_eq!(hits[1].location.function, "attributes::count_votes");
+
+    let mut log = Log { entries: Vec::new() };
+    assert_eq!(log.append(3).unwrap(), &3);
+    let hits = capture.assertions_with_id("log-append");
+    assert!(hits[0].condition);
+    assert_eq!(hits[0].location.function, "<attributes::Log as attributes::Replicate>::append");
+    log.compact();
+    let hits = capture.assertions_with_id("Log compacted");
+    assert_eq!(hits[0].location.function, "attributes::Log::compact");
+
+    assert!(block_on(install_snapshot(true)).is_ok());
+    assert!(block_on(install_snapshot(false)).is_err());
+    let hits = capture.assertions_with_id("Snapshots are installed");
+    assert_eq!(hits.iter().map(|a| a.condition).collect::<Vec<_>>(), vec![true, false]);
+    assert_eq!(hits[0].location.function, "attributes::install_snapshot");
+    assert_eq!(hits[1].details, json!({"error": {"debug": "Rejected", "display": "rejected", "sources": []}}));
+}
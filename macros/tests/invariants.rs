@@ -0,0 +1,79 @@
+use std::collections::BTreeMap;
+
+use antithesis_sdk::testing::Capture;
+use antithesis_sdk::AntithesisInvariants;
+use serde::Serialize;
+use serde_json::json;
+
+#[derive(AntithesisInvariants, Serialize)]
+#[invariant(self.applied <= self.committed, "Applied entries are committed")]
+#[invariant(id = "log-terms-sorted", self.terms.windows(2).all(|w| w[0] <= w[1]), "Log terms never decrease")]
+struct RaftLog {
+    applied: u64,
+    committed: u64,
+    terms: Vec<u64>,
+}
+
+#[derive(AntithesisInvariants)]
+#[invariant(self.0.len() <= 2, "Cache is bounded")]
+struct Cache<K: Ord>(BTreeMap<K, u32>);
+
+#[test]
+fn serialized_invariants() {
+    let capture = Capture::start();
+    let mut log = RaftLog { applied: 1, committed: 2, terms: vec![1, 1, 2] };
+    log.assert_invariants();
+    log.applied = 3;
+    log.terms.push(1);
+    log.assert_invariants();
+
+    let applied = capture.assertions_with_id("Applied entries are committed");
+    assert_eq!(applied.iter().map(|a| a.condition).collect::<Vec<_>>(), vec![true, false]);
+    assert_eq!(applied[1].details, json!({"applied": 3, "committed": 2, "terms": [1, 1, 2, 1]}));
+    assert_eq!(applied[0].location.function, "invariants::RaftLog::assert_invariants");
+
+    let sorted = capture.assertions_with_id("log-terms-sorted");
+    assert_eq!(sorted.iter().map(|a| a.condition).collect::<Vec<_>>(), vec![true, false]);
+    assert_eq!(sorted[0].message, "Log terms never decrease");
+    assert_ne!(sorted[0].location.begin_line, applied[0].location.begin_line);
+}
+
+#[test]
+fn generic_invariants() {
+    let capture = Capture::start();
+    let mut cache = Cache(BTreeMap::new());
+    for key in ["a", "b", "c"] {
+        cache.0.insert(key, 1);
+        cache.assert_invariants();
+    }
+    let hits = capture.assertions_with_id("Cache is bounded");
+    assert_eq!(hits.iter().map(|a| a.condition).collect::<Vec<_>>(), vec![true, true, false]);
+    assert_eq!(hits[2].details, json!({}));
+}
+
+// Counts how often it is serialized, to check that snapshots are only taken for emitted hits.
+#[derive(AntithesisInvariants)]
+#[invariant(self.len <= 4, "Counted buffer within capacity")]
+struct Counted<'a> {
+    len: usize,
+    serialized: &'a std::cell::Cell<u32>,
+}
+
+impl Serialize for Counted<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.serialized.set(self.serialized.get() + 1);
+        self.len.serialize(serializer)
+    }
+}
+
+// Outside of a capture, only the first pass is emitted, so the value is serialized once.
+#[test]
+fn snapshot_when_emitted() {
+    let serialized = std::cell::Cell::new(0);
+    let mut buffer = Counted { len: 0, serialized: &serialized };
+    for _ in 0..3 {
+        buffer.len += 1;
+        buffer.assert_invariants();
+    }
+    assert_eq!(serialized.get(), 1);
+}
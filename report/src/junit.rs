@@ -0,0 +1,112 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use crate::{Property, Report, Verdict};
+
+/// Renders ``report`` as JUnit XML.
+///
+/// Every property becomes a ``testcase`` named after its ``id``, and test cases
+/// are grouped into one ``testsuite`` per ``display_type``.
+pub fn to_junit(report: &Report) -> String {
+    let mut suites: BTreeMap<&str, Vec<&Property>> = BTreeMap::new();
+    for property in report.properties() {
+        suites.entry(&property.display_type).or_default().push(property);
+    }
+
+    let total = report.properties().count();
+    let failures = report.failures().count();
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(xml, "<testsuites name=\"antithesis\" tests=\"{total}\" failures=\"{failures}\">");
+    for (display_type, properties) in suites.iter() {
+        let failures = properties.iter().filter(|p| !p.passed()).count();
+        let _ = writeln!(
+            xml,
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\">",
+            escape(display_type),
+            properties.len(),
+            failures
+        );
+        for property in properties.iter() {
+            write_testcase(&mut xml, property);
+        }
+        xml.push_str("  </testsuite>\n");
+    }
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+fn write_testcase(xml: &mut String, property: &Property) {
+    let location = &property.location;
+    let _ = write!(
+        xml,
+        "    <testcase name=\"{}\" classname=\"{}\" file=\"{}\" line=\"{}\"",
+        escape(&property.id),
+        escape(&location.class),
+        escape(&location.file),
+        location.begin_line
+    );
+    match property.verdict() {
+        Verdict::Passed => xml.push_str("/>\n"),
+        Verdict::Failed(failure) => {
+            xml.push_str(">\n");
+            let _ = write!(
+                xml,
+                "      <failure message=\"{}\" type=\"{:?}\">",
+                escape(failure.describe()),
+                failure
+            );
+            if let Some((_, details)) = &property.first_false {
+                xml.push_str(&escape(&details.to_string()));
+            }
+            xml.push_str("</failure>\n");
+            xml.push_str("    </testcase>\n");
+        }
+    }
+}
+
+// Characters that XML 1.0 does not allow, even as references, are replaced by U+FFFD.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\t' | '\n' | '\r' => escaped.push(c),
+            '\u{0}'..='\u{1f}' | '\u{fffe}' | '\u{ffff}' => escaped.push(char::REPLACEMENT_CHARACTER),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{assertion, report};
+
+    #[test]
+    fn groups_by_display_type() {
+        let report = report(&[
+            assertion("a <b>", "always", "Always", true, true, true),
+            assertion("c", "always", "Always", true, true, false),
+            assertion("d", "sometimes", "Sometimes", true, false, false),
+        ]);
+        let xml = to_junit(&report);
+        assert!(xml.contains("<testsuites name=\"antithesis\" tests=\"3\" failures=\"2\">"));
+        assert!(xml.contains("<testsuite name=\"Always\" tests=\"2\" failures=\"1\">"));
+        assert!(xml.contains("<testsuite name=\"Sometimes\" tests=\"1\" failures=\"1\">"));
+        assert!(xml.contains("<testcase name=\"a &lt;b&gt;\" classname=\"c\" file=\"src/main.rs\" line=\"7\"/>"));
+        assert!(xml.contains("<failure message=\"condition was false\" type=\"ConditionFalse\">{&quot;hit&quot;:true}</failure>"));
+        assert!(xml.contains("<failure message=\"never hit\" type=\"NeverHit\"></failure>"));
+    }
+
+    #[test]
+    fn escapes_invalid_characters() {
+        assert_eq!(escape("a\u{0}b\u{1b}[0m\tc\r\n"), "a\u{fffd}b\u{fffd}[0m\tc\r\n");
+        assert_eq!(escape("\u{ffff}é'"), "\u{fffd}é&apos;");
+    }
+}
@@ -0,0 +1,275 @@
+//! Evaluates the JSONL written by the Antithesis SDK when ``ANTITHESIS_SDK_LOCAL_OUTPUT``
+//! is set, and decides which test properties passed.
+//!
+//! Each property is identified by the ``id`` of its assertions. Catalog entries
+//! (records with ``hit: false``) declare properties, and hit records report
+//! the conditions observed at runtime. Verdicts follow the rules used by the
+//! Antithesis platform:
+//!
+//! - ``Always`` and reachability properties fail if any hit has a false condition.
+//!   Unreachable assertions are always hit with a false condition.
+//! - ``Sometimes`` properties fail unless some hit has a true condition.
+//! - Properties with ``must_hit`` fail if they are never hit.
+//!
+//! A [`Report`] can be exported as [JUnit XML](to_junit) or [SARIF](to_sarif).
+
+use std::collections::BTreeMap;
+
+use antithesis_sdk::protocol::{Assertion, AssertType, Location, Record};
+use serde_json::Value;
+
+mod junit;
+mod sarif;
+
+pub use junit::to_junit;
+pub use sarif::to_sarif;
+
+/// Whether a property passed, and why not if it did not.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Verdict {
+    Passed,
+    Failed(Failure),
+}
+
+/// The reason a property failed.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Failure {
+    /// A ``must_hit`` property was never reached.
+    NeverHit,
+    /// An ``Always`` or reachability property was hit with a false condition.
+    ConditionFalse,
+    /// A ``Sometimes`` property was hit, but never with a true condition.
+    NeverTrue,
+}
+
+impl Failure {
+    pub fn describe(&self) -> &'static str {
+        match self {
+            Failure::NeverHit => "never hit",
+            Failure::ConditionFalse => "condition was false",
+            Failure::NeverTrue => "condition was never true",
+        }
+    }
+}
+
+/// Everything known about one property, keyed by its ``id``.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Property {
+    pub id: String,
+    pub message: String,
+    pub assert_type: AssertType,
+    pub display_type: String,
+    pub must_hit: bool,
+    /// Location of the first record seen for this property.
+    pub location: Location<'static>,
+    /// Whether a hit with a true condition was seen.
+    pub seen_true: bool,
+    /// Whether a hit with a false condition was seen.
+    pub seen_false: bool,
+    /// Location and details of the first hit with a false condition.
+    pub first_false: Option<(Location<'static>, Value)>,
+}
+
+impl Property {
+    fn declare(assertion: &Assertion<'static>) -> Self {
+        Property {
+            id: assertion.id.to_string(),
+            message: assertion.message.to_string(),
+            assert_type: assertion.assert_type,
+            display_type: assertion.display_type.to_string(),
+            must_hit: assertion.must_hit,
+            location: assertion.location.clone(),
+            seen_true: false,
+            seen_false: false,
+            first_false: None,
+        }
+    }
+
+    fn record_hit(&mut self, assertion: Assertion<'static>) {
+        if assertion.condition {
+            self.seen_true = true;
+        } else {
+            self.seen_false = true;
+            if self.first_false.is_none() {
+                self.first_false = Some((assertion.location, assertion.details));
+            }
+        }
+    }
+
+    pub fn was_hit(&self) -> bool {
+        self.seen_true || self.seen_false
+    }
+
+    pub fn verdict(&self) -> Verdict {
+        if !self.was_hit() {
+            return if self.must_hit {
+                Verdict::Failed(Failure::NeverHit)
+            } else {
+                Verdict::Passed
+            };
+        }
+        match self.assert_type {
+            AssertType::Always | AssertType::Reachability if self.seen_false => {
+                Verdict::Failed(Failure::ConditionFalse)
+            }
+            AssertType::Sometimes if !self.seen_true => Verdict::Failed(Failure::NeverTrue),
+            _ => Verdict::Passed,
+        }
+    }
+
+    pub fn passed(&self) -> bool {
+        self.verdict() == Verdict::Passed
+    }
+}
+
+/// The properties found in one or more local output files.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Report {
+    properties: BTreeMap<String, Property>,
+}
+
+impl Report {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the assertion records among ``records`` to the report.
+    /// Other kinds of records are ignored.
+    pub fn add_records(&mut self, records: impl IntoIterator<Item = Record<'static>>) {
+        for record in records {
+            if let Record::Assertion(assertion) = record {
+                self.add_assertion(assertion);
+            }
+        }
+    }
+
+    /// Parses ``contents`` as local output and adds it to the report.
+    pub fn add_jsonl(&mut self, contents: &str) -> Result<(), serde_json::Error> {
+        let records = antithesis_sdk::protocol::parse_jsonl(contents)?;
+        self.add_records(records);
+        Ok(())
+    }
+
+    fn add_assertion(&mut self, assertion: Assertion<'static>) {
+        let property = self
+            .properties
+            .entry(assertion.id.to_string())
+            .or_insert_with(|| Property::declare(&assertion));
+        if assertion.hit {
+            property.record_hit(assertion);
+        }
+    }
+
+    /// All properties, ordered by ``id``.
+    pub fn properties(&self) -> impl Iterator<Item = &Property> {
+        self.properties.values()
+    }
+
+    pub fn property(&self, id: &str) -> Option<&Property> {
+        self.properties.get(id)
+    }
+
+    pub fn failures(&self) -> impl Iterator<Item = &Property> {
+        self.properties().filter(|property| !property.passed())
+    }
+
+    pub fn passed(&self) -> bool {
+        self.failures().next().is_none()
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use serde_json::json;
+
+    pub(crate) fn assertion(id: &str, assert_type: &str, display_type: &str, must_hit: bool, hit: bool, condition: bool) -> String {
+        json!({"antithesis_assert": {
+            "assert_type": assert_type,
+            "display_type": display_type,
+            "condition": condition,
+            "message": id,
+            "location": {"class": "c", "function": "c::f", "file": "src/main.rs", "begin_line": 7, "begin_column": 5},
+            "hit": hit,
+            "must_hit": must_hit,
+            "id": id,
+            "details": {"hit": hit},
+        }})
+        .to_string()
+    }
+
+    pub(crate) fn report(lines: &[String]) -> Report {
+        let mut report = Report::new();
+        report.add_jsonl(&lines.join("\n")).unwrap();
+        report
+    }
+
+    fn verdict(report: &Report, id: &str) -> Verdict {
+        report.property(id).unwrap().verdict()
+    }
+
+    #[test]
+    fn always() {
+        let report = report(&[
+            assertion("ok", "always", "Always", true, false, false),
+            assertion("ok", "always", "Always", true, true, true),
+            assertion("bad", "always", "Always", true, false, false),
+            assertion("bad", "always", "Always", true, true, true),
+            assertion("bad", "always", "Always", true, true, false),
+            assertion("missed", "always", "Always", true, false, false),
+            assertion("optional", "always", "AlwaysOrUnreachable", false, false, false),
+        ]);
+        assert_eq!(verdict(&report, "ok"), Verdict::Passed);
+        assert_eq!(verdict(&report, "bad"), Verdict::Failed(Failure::ConditionFalse));
+        assert_eq!(verdict(&report, "missed"), Verdict::Failed(Failure::NeverHit));
+        assert_eq!(verdict(&report, "optional"), Verdict::Passed);
+        assert_eq!(report.property("bad").unwrap().first_false.as_ref().unwrap().1, json!({"hit": true}));
+        assert!(!report.passed());
+    }
+
+    #[test]
+    fn sometimes() {
+        let report = report(&[
+            assertion("ok", "sometimes", "Sometimes", true, false, false),
+            assertion("ok", "sometimes", "Sometimes", true, true, false),
+            assertion("ok", "sometimes", "Sometimes", true, true, true),
+            assertion("bad", "sometimes", "Sometimes", true, false, false),
+            assertion("bad", "sometimes", "Sometimes", true, true, false),
+            assertion("missed", "sometimes", "Sometimes", true, false, false),
+        ]);
+        assert_eq!(verdict(&report, "ok"), Verdict::Passed);
+        assert_eq!(verdict(&report, "bad"), Verdict::Failed(Failure::NeverTrue));
+        assert_eq!(verdict(&report, "missed"), Verdict::Failed(Failure::NeverHit));
+    }
+
+    #[test]
+    fn reachability() {
+        let report = report(&[
+            assertion("reached", "reachability", "Reachable", true, false, true),
+            assertion("reached", "reachability", "Reachable", true, true, true),
+            assertion("missed", "reachability", "Reachable", true, false, true),
+            assertion("avoided", "reachability", "Unreachable", false, false, false),
+            assertion("entered", "reachability", "Unreachable", false, false, false),
+            assertion("entered", "reachability", "Unreachable", false, true, false),
+        ]);
+        assert_eq!(verdict(&report, "reached"), Verdict::Passed);
+        assert_eq!(verdict(&report, "missed"), Verdict::Failed(Failure::NeverHit));
+        assert_eq!(verdict(&report, "avoided"), Verdict::Passed);
+        assert_eq!(verdict(&report, "entered"), Verdict::Failed(Failure::ConditionFalse));
+    }
+
+    #[test]
+    fn hits_without_catalog_entry() {
+        let report = report(&[assertion("raw", "always", "Always", true, true, true)]);
+        assert_eq!(verdict(&report, "raw"), Verdict::Passed);
+    }
+
+    #[test]
+    fn merges_files() {
+        let mut report = Report::new();
+        report.add_jsonl(&assertion("split", "sometimes", "Sometimes", true, false, false)).unwrap();
+        report.add_jsonl(&assertion("split", "sometimes", "Sometimes", true, true, true)).unwrap();
+        assert_eq!(verdict(&report, "split"), Verdict::Passed);
+        assert!(report.passed());
+    }
+}
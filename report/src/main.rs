@@ -0,0 +1,107 @@
+use std::env;
+use std::fs;
+use std::process::ExitCode;
+
+use antithesis_report::{to_junit, to_sarif, Report, Verdict};
+use antithesis_sdk::LOCAL_OUTPUT;
+
+const USAGE: &str = "\
+Usage: antithesis-report [--format text|junit|sarif] [FILE...]
+
+Reads the JSONL files written by the Antithesis SDK when ANTITHESIS_SDK_LOCAL_OUTPUT
+is set, and reports which test properties passed. When no FILE is given, the file
+named by ANTITHESIS_SDK_LOCAL_OUTPUT is read.
+
+The report is printed to stdout as text (the default), JUnit XML or SARIF.
+
+Exits with status 1 if any property failed, and 2 if the input could not be read.";
+
+enum Format {
+    Text,
+    Junit,
+    Sarif,
+}
+
+fn main() -> ExitCode {
+    let mut format = Format::Text;
+    let mut files: Vec<String> = Vec::new();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return ExitCode::SUCCESS;
+            }
+            "--format" => {
+                format = match args.next().as_deref() {
+                    Some("text") => Format::Text,
+                    Some("junit") => Format::Junit,
+                    Some("sarif") => Format::Sarif,
+                    _ => {
+                        eprintln!("{USAGE}");
+                        return ExitCode::from(2);
+                    }
+                }
+            }
+            _ => files.push(arg),
+        }
+    }
+    if files.is_empty() {
+        match env::var(LOCAL_OUTPUT) {
+            Ok(file) => files.push(file),
+            Err(_) => {
+                eprintln!("{USAGE}");
+                return ExitCode::from(2);
+            }
+        }
+    }
+
+    let mut report = Report::new();
+    for file in files.iter() {
+        let result = fs::read_to_string(file)
+            .map_err(|e| e.to_string())
+            .and_then(|contents| report.add_jsonl(&contents).map_err(|e| e.to_string()));
+        if let Err(e) = result {
+            eprintln!("Unable to read '{file}' - {e}");
+            return ExitCode::from(2);
+        }
+    }
+
+    match format {
+        Format::Text => print_text(&report),
+        Format::Junit => print!("{}", to_junit(&report)),
+        Format::Sarif => println!("{:#}", to_sarif(&report)),
+    }
+    if report.passed() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn print_text(report: &Report) {
+    let mut total = 0;
+    let mut failed = 0;
+    for property in report.properties() {
+        total += 1;
+        let location = &property.location;
+        match property.verdict() {
+            Verdict::Passed => println!("PASS  {:<20} {}", property.display_type, property.id),
+            Verdict::Failed(failure) => {
+                failed += 1;
+                println!(
+                    "FAIL  {:<20} {} ({})",
+                    property.display_type,
+                    property.id,
+                    failure.describe()
+                );
+                println!("      at {}:{}:{}", location.file, location.begin_line, location.begin_column);
+                if let Some((_, details)) = &property.first_false {
+                    println!("      details: {details}");
+                }
+            }
+        }
+    }
+    println!();
+    println!("{} properties, {} passed, {} failed", total, total - failed, failed);
+}
@@ -0,0 +1,97 @@
+use serde_json::{json, Value};
+
+use crate::{Property, Report, Verdict};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Renders ``report`` as a SARIF 2.1.0 log.
+///
+/// Every property becomes a rule, and every failed property becomes a result
+/// located where it was first seen failing, or at its catalog entry when it was never hit.
+pub fn to_sarif(report: &Report) -> Value {
+    let rules: Vec<Value> = report.properties().map(rule).collect();
+    let results: Vec<Value> = report.properties().filter_map(result).collect();
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "antithesis-report",
+                    "informationUri": "https://antithesis.com/docs/using_antithesis/sdk/rust/",
+                    "rules": rules,
+                }
+            },
+            "results": results,
+        }]
+    })
+}
+
+fn rule(property: &Property) -> Value {
+    json!({
+        "id": property.id,
+        "shortDescription": { "text": property.message },
+        "properties": {
+            "display_type": property.display_type,
+            "must_hit": property.must_hit,
+        },
+    })
+}
+
+fn result(property: &Property) -> Option<Value> {
+    let failure = match property.verdict() {
+        Verdict::Passed => return None,
+        Verdict::Failed(failure) => failure,
+    };
+    let (location, details) = match &property.first_false {
+        Some((location, details)) => (location, details.clone()),
+        None => (&property.location, Value::Null),
+    };
+    Some(json!({
+        "ruleId": property.id,
+        "level": "error",
+        "message": {
+            "text": format!("{} property '{}' failed: {}", property.display_type, property.message, failure.describe()),
+        },
+        "locations": [{
+            "physicalLocation": {
+                "artifactLocation": { "uri": location.file },
+                "region": {
+                    "startLine": location.begin_line,
+                    "startColumn": location.begin_column,
+                },
+            },
+            "logicalLocations": [{
+                "fullyQualifiedName": location.function,
+                "kind": "function",
+            }],
+        }],
+        "properties": { "details": details },
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{assertion, report};
+
+    #[test]
+    fn reports_failures_only() {
+        let report = report(&[
+            assertion("ok", "always", "Always", true, true, true),
+            assertion("bad", "always", "Always", true, true, false),
+        ]);
+        let sarif = to_sarif(&report);
+        let run = &sarif["runs"][0];
+        assert_eq!(sarif["version"], "2.1.0");
+        assert_eq!(run["tool"]["driver"]["rules"].as_array().unwrap().len(), 2);
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0]["ruleId"], "bad");
+        let location = &results[0]["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "src/main.rs");
+        assert_eq!(location["region"]["startLine"], 7);
+        assert_eq!(location["region"]["startColumn"], 5);
+        assert_eq!(results[0]["properties"]["details"], json!({"hit": true}));
+    }
+}
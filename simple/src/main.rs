@@ -87,6 +87,8 @@ fn assert_demo() {
     assert_sometimes_greater_than_or_equal_to!(3, 100, "not right");
 
     assert_sometimes_all!({a: true, b: false}, "not all right");
+
+    assert_always_eq!(json!({"term": 3, "log": [1, 2]}), json!({"term": 3, "log": [1, 5]}), "replicas agree");
 }
 
 pub fn main() {
@@ -99,4 +101,6 @@ pub fn main() {
     lifecycle_demo();
 
     assert_demo();
+
+    lifecycle::shutdown();
 }